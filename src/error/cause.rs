@@ -29,19 +29,47 @@ pub enum InvalidTag {
     FuncType(Option<u8>),
     /// An invalid [`ImportDesc`](crate::module::ImportDesc).
     ImportDesc(Option<u8>),
+    /// An invalid [`ExportDesc`](crate::module::ExportDesc).
+    ExportDesc(Option<u8>),
+    /// An invalid **`elemkind`** in an [`ElemSegment`](crate::module::ElemSegment).
+    ElemKind(Option<u8>),
+    /// An invalid [composite type] in a [recursive type](crate::types::rec_type()).
+    ///
+    /// [composite type]: https://webassembly.github.io/gc/core/binary/types.html#composite-types
+    CompType(Option<u8>),
+    /// An invalid [`CatchClause`](crate::isa::CatchClause) in a `try_table` instruction.
+    CatchClause(Option<u8>),
+    /// An invalid [`ResumeHandler`](crate::isa::ResumeHandler) in a `resume` or `resume_throw`
+    /// instruction.
+    ResumeHandler(Option<u8>),
+    /// An invalid [`AtomicOrdering`](crate::isa::AtomicOrdering) in an atomic instruction.
+    AtomicOrdering(Option<u8>),
 }
 
 impl Display for InvalidTag {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let (value, value_width) = match self {
             Self::ModuleSectionId(b) => (Some(u32::from(*b)), 4),
-            Self::FuncType(b) | Self::ImportDesc(b) => (b.map(u32::from), 4),
+            Self::FuncType(b)
+            | Self::ImportDesc(b)
+            | Self::ExportDesc(b)
+            | Self::ElemKind(b)
+            | Self::CompType(b)
+            | Self::CatchClause(b)
+            | Self::ResumeHandler(b)
+            | Self::AtomicOrdering(b) => (b.map(u32::from), 4),
         };
 
         let name = match self {
             Self::ModuleSectionId(_) => "module section ID",
             Self::FuncType(_) => "function type",
             Self::ImportDesc(_) => "import desc",
+            Self::ExportDesc(_) => "export desc",
+            Self::ElemKind(_) => "element kind",
+            Self::CompType(_) => "composite type",
+            Self::CatchClause(_) => "catch clause",
+            Self::ResumeHandler(_) => "resume handler",
+            Self::AtomicOrdering(_) => "atomic ordering",
         };
 
         if let Some(value) = value {
@@ -82,19 +110,66 @@ pub enum InvalidFlags {
     Limits(InvalidFlagsValue<u8>),
     /// Invalid flags for a [`GlobalType`](crate::types::GlobalType).
     GlobalType(InvalidFlagsValue<u8>),
+    /// Invalid mutability flags for a [`FieldType`](crate::types::FieldType).
+    FieldType(InvalidFlagsValue<u8>),
+    /// Invalid flags for an [`ElemSegment`](crate::module::ElemSegment).
+    ///
+    /// Contains `None` if the flags could not be parsed, or `Some` flags value containing
+    /// invalid flags.
+    ElemSegment(Option<core::num::NonZeroU32>),
+    /// Invalid flags for a [`DataSegment`](crate::module::DataSegment).
+    ///
+    /// Contains `None` if the flags could not be parsed, or `Some` invalid flags value.
+    DataSegment(Option<core::num::NonZeroU32>),
+    /// Invalid **`castflags`** for a `br_on_cast` or `br_on_cast_fail` instruction.
+    CastFlags(InvalidFlagsValue<u8>),
 }
 
 impl Display for InvalidFlags {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        let (name, invalid) = match self {
-            Self::Limits(e) => ("limits", e),
-            Self::GlobalType(e) => ("global type", e),
+        fn widen(flags: &InvalidFlagsValue<u8>) -> InvalidFlagsValue<u32> {
+            match *flags {
+                InvalidFlagsValue::Invalid { value, invalid } => InvalidFlagsValue::Invalid {
+                    value: value.into(),
+                    invalid: invalid.into(),
+                },
+                InvalidFlagsValue::Missing => InvalidFlagsValue::Missing,
+            }
+        }
+
+        let (name, invalid, width) = match self {
+            Self::Limits(e) => ("limits", widen(e), 4),
+            Self::GlobalType(e) => ("global type", widen(e), 4),
+            Self::FieldType(e) => ("field type", widen(e), 4),
+            Self::ElemSegment(e) => (
+                "element segment",
+                e.map_or(InvalidFlagsValue::Missing, |value| {
+                    InvalidFlagsValue::Invalid {
+                        value: value.get(),
+                        invalid: value.get() & !crate::module::ElemSegment::FLAGS_ALL,
+                    }
+                }),
+                10,
+            ),
+            Self::DataSegment(e) => (
+                "data segment",
+                e.map_or(InvalidFlagsValue::Missing, |value| {
+                    let unknown = value.get() & !crate::module::DataSegment::FLAGS_ALL;
+                    InvalidFlagsValue::Invalid {
+                        value: value.get(),
+                        // Data segments can't be both passive and have an explicit memory index
+                        invalid: if unknown == 0 { value.get() } else { unknown },
+                    }
+                }),
+                10,
+            ),
+            Self::CastFlags(e) => ("cast", widen(e), 4),
         };
 
         match invalid {
             InvalidFlagsValue::Invalid { value, invalid } => write!(
                 f,
-                "the {name} flags {value:#04X} contains invalid flag(s): {invalid:#04X}"
+                "the {name} flags {value:#0width$X} contains invalid flag(s): {invalid:#0width$X}"
             ),
             InvalidFlagsValue::Missing => write!(f, "missing {name} flags"),
         }
@@ -112,6 +187,9 @@ impl std::error::Error for InvalidFlags {}
 pub enum LimitsComponent {
     Minimum,
     Maximum,
+    /// The base-2 logarithm of the page size, introduced as part of the
+    /// [custom page sizes proposal](https://github.com/WebAssembly/custom-page-sizes).
+    PageSize,
 }
 
 impl Display for LimitsComponent {
@@ -119,6 +197,7 @@ impl Display for LimitsComponent {
         f.write_str(match self {
             Self::Minimum => "minimum",
             Self::Maximum => "maximum",
+            Self::PageSize => "page size",
         })
     }
 }
@@ -201,6 +280,13 @@ pub enum ErrorCause {
     ///
     /// [`TypeIdx`]: crate::module::TypeIdx
     BlockType(Option<core::num::NonZeroI64>),
+    /// A [`HeapType`](crate::types::HeapType) could not be parsed.
+    /// - Contains `None` if the end of input was unexpectedly encountered.
+    /// - Contains `Some` negative value if an unrecognized abstract heap type was encountered.
+    /// - Contains `Some` positive value if the parsed [`TypeIdx`] was too large.
+    ///
+    /// [`TypeIdx`]: crate::module::TypeIdx
+    HeapType(Option<core::num::NonZeroI64>),
     /// A [`ValType`](crate::types::ValType) was not valid.
     ///
     /// - Contains `None` if a [`BlockType::Empty`] was parsed.
@@ -213,20 +299,69 @@ pub enum ErrorCause {
         index_type: crate::types::IdxType,
         component: LimitsComponent,
     },
-    RefType(crate::types::ValType),
+    /// A [`RefType`](crate::types::RefType) could not be parsed.
+    ///
+    /// - Contains `None` if the end of input was unexpectedly encountered.
+    /// - Contains `Some` value if the encoding of some other type was encountered.
+    RefType(Option<core::num::NonZeroI64>),
     #[non_exhaustive]
     MemType,
     #[non_exhaustive]
     TableType,
     #[non_exhaustive]
+    TableInit,
+    #[non_exhaustive]
     GlobalType,
     #[non_exhaustive]
+    GlobalInit,
+    #[non_exhaustive]
+    ElemOffset,
+    #[non_exhaustive]
+    DataOffset,
+    DataSegmentContents(LengthMismatch),
+    #[non_exhaustive]
     TagType,
     #[non_exhaustive]
     ImportDesc {
         kind: u8,
     },
     Import(ImportComponent),
+    /// A compact [`ImportGroup`](crate::module::ImportGroup) was encountered where only a single
+    /// [`Import`](crate::module::Import) was expected.
+    #[non_exhaustive]
+    ImportGroup,
+    #[non_exhaustive]
+    ExportDesc {
+        kind: u8,
+    },
+    #[non_exhaustive]
+    ExportName,
+    /// An [`Export`](crate::module::Export) had the same name as an export before it.
+    #[non_exhaustive]
+    DuplicateExport {
+        /// The index of the duplicate export.
+        index: u32,
+    },
+    #[non_exhaustive]
+    FuncBodySize,
+    FuncBodyContents(LengthMismatch),
+    #[non_exhaustive]
+    FuncBodyEnd,
+    #[non_exhaustive]
+    LocalsOverflow,
+    #[non_exhaustive]
+    StartSec,
+    #[non_exhaustive]
+    DataCountSec,
+    /// The number of data segments in the *data section* did not match the count specified in
+    /// the *data count section*.
+    #[non_exhaustive]
+    DataCount {
+        /// The number of data segments specified by the *data count section*.
+        expected: u32,
+        /// The number of data segments in the *data section*.
+        actual: u32,
+    },
     ModuleSectionOrder(crate::ordering::OrderingError<crate::module::ModuleSectionOrder>),
     Opcode(crate::isa::InvalidOpcode),
     #[non_exhaustive]
@@ -235,7 +370,15 @@ pub enum ErrorCause {
         reason: crate::isa::InvalidInstr,
     },
     Expr(crate::isa::InvalidExpr),
+    ConstExpr(crate::isa::InvalidConstExpr),
     MemArg(MemArgComponent),
+    BranchHint(crate::module::custom::InvalidBranchHint),
+    /// An instruction or type was encountered that requires proposals that are not enabled.
+    ///
+    /// Contains the set of required proposals that were disabled.
+    FeatureDisabled(crate::features::WasmFeatures),
+    /// An instruction in a function body was not well-typed.
+    FuncValidation(crate::isa::InvalidFuncBody),
 }
 
 crate::static_assert::check_size!(ErrorCause, <= 16);
@@ -315,8 +458,20 @@ impl Display for ErrorCause {
                     write!(f, "type index in block type {block_type} is too large, maximum 32-bit value is {}", u32::MAX)
                 }
             }
+            Self::HeapType(None) => f.write_str("expected abstract heap type or type index"),
+            Self::HeapType(Some(heap_type)) => {
+                if heap_type.get() < 0 {
+                    write!(f, "{heap_type} is not a valid abstract heap type")
+                } else {
+                    write!(f, "type index in heap type {heap_type} is too large, maximum 32-bit value is {}", u32::MAX)
+                }
+            }
             Self::ValType(None) => f.write_str("expected valtype but got empty block type"),
             Self::ValType(Some(index)) => write!(f, "expected valtype but got type index {index}"),
+            Self::Limits {
+                component: LimitsComponent::PageSize,
+                ..
+            } => f.write_str("could not parse page size for limit"),
             Self::Limits {
                 index_type,
                 component,
@@ -328,20 +483,59 @@ impl Display for ErrorCause {
                 })?;
                 write!(f, "-bit integer {component} bound for limit")
             }
-            Self::RefType(actual) => write!(f, "expected reftype but got {actual}"),
+            Self::RefType(None) => f.write_str("expected reftype"),
+            Self::RefType(Some(encoding)) => match encoding.get() {
+                -1 => f.write_str("expected reftype but got i32"),
+                -2 => f.write_str("expected reftype but got i64"),
+                -3 => f.write_str("expected reftype but got f32"),
+                -4 => f.write_str("expected reftype but got f64"),
+                -5 => f.write_str("expected reftype but got v128"),
+                -64 => f.write_str("expected reftype but got empty block type"),
+                index if index > 0 => write!(f, "expected reftype but got type index {index}"),
+                _ => write!(f, "{encoding} is not a valid reftype encoding"),
+            },
             Self::MemType => f.write_str("could not parse memory type"),
             Self::TableType => f.write_str("could not parse table type"),
+            Self::TableInit => f.write_str("could not parse table initializer expression"),
             Self::GlobalType => f.write_str("could not parse global type"),
+            Self::GlobalInit => f.write_str("could not parse global initializer expression"),
+            Self::DataOffset => f.write_str("could not parse data segment offset expression"),
+            Self::DataSegmentContents(e) => e.print("data segment contents", f),
+            Self::ElemOffset => f.write_str("could not parse element segment offset expression"),
             Self::TagType => f.write_str("could not parse tag type"),
             Self::ImportDesc { kind } => write!(f, "error parsing importdesc kind {kind:#04X}"),
             Self::Import(field) => write!(f, "could not parse import: missing {field}"),
+            Self::ImportGroup => {
+                f.write_str("expected a single import, but found a compact import group")
+            }
+            Self::ExportDesc { kind } => write!(f, "error parsing exportdesc kind {kind:#04X}"),
+            Self::ExportName => f.write_str("could not parse export: missing export name"),
+            Self::DuplicateExport { index } => {
+                write!(f, "export #{index} has the same name as a previous export")
+            }
+            Self::FuncBodySize => f.write_str("expected function body size"),
+            Self::FuncBodyContents(e) => e.print("function body", f),
+            Self::FuncBodyEnd => f.write_str("expected end of function body after final `end` instruction"),
+            Self::LocalsOverflow => write!(f, "function declares more than {} local variables", u32::MAX),
+            Self::StartSec => f.write_str("could not parse start section"),
+            Self::DataCountSec => f.write_str("could not parse data count section"),
+            Self::DataCount { expected, actual } => write!(
+                f,
+                "data count section specified {expected} data segments, but data section contained {actual}"
+            ),
             Self::ModuleSectionOrder(order) => Display::fmt(order, f),
             Self::Opcode(bad) => Display::fmt(bad, f),
             Self::Instr { opcode, reason } => {
                 write!(f, "could not parse `{opcode}` instruction {reason}")
             }
             Self::Expr(bad) => Display::fmt(bad, f),
+            Self::ConstExpr(bad) => Display::fmt(bad, f),
             Self::MemArg(bad) => write!(f, "could not parse memarg: {bad}"),
+            Self::BranchHint(bad) => Display::fmt(bad, f),
+            Self::FeatureDisabled(disabled) => {
+                write!(f, "required proposals are not enabled: {disabled}")
+            }
+            Self::FuncValidation(bad) => write!(f, "invalid function body: {bad}"),
         }
     }
 }
@@ -358,6 +552,9 @@ impl std::error::Error for ErrorCause {
             Self::Opcode(e) => e,
             Self::Instr { reason, .. } => reason,
             Self::Expr(e) => e,
+            Self::ConstExpr(e) => e,
+            Self::BranchHint(e) => e,
+            Self::FuncValidation(e) => e,
             _ => return None,
         })
     }
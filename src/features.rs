@@ -0,0 +1,200 @@
+//! The [`WasmFeatures`] bitset specifies which WebAssembly proposals are allowed when parsing.
+
+use crate::error::{ErrorCause, ErrorKind, ErrorSource};
+
+macro_rules! wasm_features {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident = $bit:literal => $proposal:ident;
+    )*) => {
+        #[allow(missing_docs)]
+        impl WasmFeatures {
+            $(
+                $(#[$meta])*
+                pub const $name: Self = Self(1 << $bit);
+            )*
+
+            /// Every proposal recognized by [`nom-wasm`](crate).
+            pub const ALL: Self = Self(0 $(| (1 << $bit))*);
+
+            const NAMES: &'static [(Self, &'static str)] = &[$((Self::$name, stringify!($proposal)),)*];
+        }
+
+        /// Gets the [`WasmFeatures`] flag corresponding to a proposal name used in
+        /// [`instr_definitions::all!`](crate::isa).
+        macro_rules! from_proposal {
+            $(
+                ($proposal) => { $crate::features::WasmFeatures::$name };
+            )*
+        }
+
+        pub(crate) use from_proposal;
+    };
+}
+
+/// A set of WebAssembly proposals, which are either enabled or disabled.
+///
+/// Parsers that take a [`WasmFeatures`] set, such as [`isa::instr_with_features()`] and
+/// [`ValType::parse_with_features()`], fail with an [`ErrorCause::FeatureDisabled`] when an
+/// instruction or type that was introduced by a disabled proposal is encountered.
+///
+/// To check an entire module, use [`Module::parse_with_features()`]. The contents of its sections
+/// are checked as they are parsed.
+///
+/// [`isa::instr_with_features()`]: crate::isa::instr_with_features
+/// [`ValType::parse_with_features()`]: crate::types::ValType::parse_with_features
+/// [`Module::parse_with_features()`]: crate::module::Module::parse_with_features
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct WasmFeatures(u32);
+
+wasm_features! {
+    /// The [WebAssembly 1.0] specification, which is always required.
+    ///
+    /// [WebAssembly 1.0]: https://www.w3.org/TR/wasm-core-1/
+    MVP = 0 => mvp;
+    /// The [sign extension operators proposal](https://github.com/WebAssembly/sign-extension-ops).
+    SIGN_EXT = 1 => sign_ext;
+    /// The [non-trapping float-to-int conversions proposal](https://github.com/WebAssembly/nontrapping-float-to-int-conversions).
+    NONTRAPPING_FPTOINT = 2 => nontrapping_fptoint;
+    /// The [multi-value proposal](https://github.com/WebAssembly/multi-value).
+    MULTI_VALUE = 3 => multi_value;
+    /// The [bulk memory operations proposal](https://github.com/WebAssembly/bulk-memory-operations).
+    BULK_MEMORY = 4 => bulk_memory;
+    /// The [reference types proposal](https://github.com/WebAssembly/reference-types).
+    REFERENCE_TYPES = 5 => reference_types;
+    /// The [fixed-width SIMD proposal](https://github.com/WebAssembly/simd).
+    SIMD128 = 6 => simd128;
+    /// The [relaxed SIMD proposal](https://github.com/WebAssembly/relaxed-simd).
+    RELAXED_SIMD = 7 => relaxed_simd;
+    /// The [threads proposal](https://github.com/WebAssembly/threads).
+    ATOMICS = 8 => atomics;
+    /// The [tail call proposal](https://github.com/WebAssembly/tail-call).
+    TAIL_CALL = 9 => tail_call;
+    /// The [exception handling proposal](https://github.com/WebAssembly/exception-handling).
+    EXCEPTION_HANDLING = 10 => exception_handling;
+    /// The [typed function references proposal](https://github.com/WebAssembly/function-references).
+    FUNCTION_REFERENCES = 11 => function_references;
+    /// The [garbage collection proposal](https://github.com/WebAssembly/gc).
+    GC = 12 => gc;
+    /// The [64-bit memory proposal](https://github.com/WebAssembly/memory64).
+    MEMORY64 = 13 => memory64;
+    /// The [custom page sizes proposal](https://github.com/WebAssembly/custom-page-sizes).
+    CUSTOM_PAGE_SIZES = 14 => custom_page_sizes;
+    /// The [half precision proposal](https://github.com/WebAssembly/half-precision).
+    FP16 = 15 => fp16;
+    /// The [wide arithmetic proposal](https://github.com/WebAssembly/wide-arithmetic).
+    WIDE_ARITHMETIC = 16 => wide_arithmetic;
+    /// The [stack switching proposal](https://github.com/WebAssembly/stack-switching).
+    STACK_SWITCHING = 17 => stack_switching;
+    /// The [shared-everything threads proposal](https://github.com/WebAssembly/shared-everything-threads).
+    SHARED_EVERYTHING_THREADS = 18 => shared_everything_threads;
+    /// The [multi-memory proposal](https://github.com/WebAssembly/multi-memory).
+    MULTI_MEMORY = 19 => multi_memory;
+    /// The [extended constant expressions proposal](https://github.com/WebAssembly/extended-const).
+    EXTENDED_CONST = 20 => extended_const;
+}
+
+impl WasmFeatures {
+    /// An empty set, with no proposals enabled.
+    pub const EMPTY: Self = Self(0);
+
+    /// Returns `true` if every proposal in `other` is also enabled in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no proposals are enabled.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the proposals that are enabled in either `self` or `other`.
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the proposals in `self` that are **not** enabled in `other`.
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Gets the bits used to represent the set.
+    #[inline]
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns an [`Iterator`] over the names of each enabled proposal, such as `"simd128"`.
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        Self::NAMES
+            .iter()
+            .filter(move |(flag, _)| self.contains(*flag))
+            .map(|(_, name)| *name)
+    }
+
+    /// Checks that all of the `required` proposals are enabled.
+    pub(crate) fn check<'a, E: ErrorSource<'a>>(
+        self,
+        input: &'a [u8],
+        required: Self,
+    ) -> Result<(), nom::Err<E>> {
+        let disabled = required.difference(self);
+        if disabled.is_empty() {
+            Ok(())
+        } else {
+            Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                ErrorKind::Verify,
+                ErrorCause::FeatureDisabled(disabled),
+            )))
+        }
+    }
+}
+
+impl core::ops::BitOr for WasmFeatures {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl core::ops::BitOrAssign for WasmFeatures {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl core::fmt::Display for WasmFeatures {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, name) in self.names().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            f.write_str(name)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for WasmFeatures {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("WasmFeatures(")?;
+        for (i, name) in self.names().enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
+            }
+
+            f.write_str(name)?;
+        }
+        f.write_str(")")
+    }
+}
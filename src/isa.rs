@@ -2,31 +2,53 @@
 //!
 //! [WebAssembly instructions]: https://webassembly.github.io/spec/core/binary/instructions.html
 
+mod atomic_ordering;
 mod br_table_targets;
+mod catch_clauses;
+mod const_expr;
 mod expr;
+mod feature_check;
+mod func_validator;
 mod instr_definitions;
 mod invalid_instr;
 mod invalid_opcode;
 mod mem_arg;
 mod opcode;
 mod opcode_enums;
+mod parse_branch_hint;
 mod parse_instr;
 mod parse_instruction;
+mod resume_handlers;
 
 #[cfg_attr(doc_cfg, doc(cfg(feature = "allocator-api2")))]
 #[cfg(feature = "allocator-api2")]
 pub mod instructions;
 
 pub use crate::module::LabelIdx;
+pub use atomic_ordering::AtomicOrdering;
 pub use br_table_targets::BrTableTargets;
-pub use expr::{expr, InvalidExpr};
+pub use catch_clauses::{CatchClause, CatchClauseParser, CatchClauses};
+pub use const_expr::{
+    const_expr, const_expr_with_features, ConstExprEvaluator, ConstValue, InvalidConstExpr,
+};
+pub use expr::{expr, Expr, ExprParser, InvalidExpr};
+pub use feature_check::{expr_with_features, instr_with_features};
+pub use func_validator::{FuncValidator, InvalidFuncBody, ModuleContext};
 pub use invalid_instr::InvalidInstr;
 pub use invalid_opcode::InvalidOpcode;
 pub use mem_arg::{Align, MemArg};
 pub use opcode::Opcode;
-pub use opcode_enums::{ByteOpcode, FCPrefixedOpcode, FEPrefixedOpcode, V128Opcode};
+pub use opcode_enums::{
+    ByteOpcode, FBPrefixedOpcode, FCPrefixedOpcode, FEPrefixedOpcode, V128Opcode,
+};
+pub use parse_branch_hint::ParseBranchHint;
 pub use parse_instr::{ParseInstr, ParseInstrError, Result};
 pub use parse_instruction::instr;
+pub use resume_handlers::{ResumeHandler, ResumeHandlerParser, ResumeHandlers};
+
+pub(crate) use const_expr::init_expr_features;
+pub(crate) use func_validator::validate_func_body;
+pub(crate) use parse_branch_hint::expr_with_branch_hints;
 
 /// A WebAssembly [**`laneidx`**] refers to a lane within a 128-bit vector.
 ///
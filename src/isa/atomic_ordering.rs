@@ -0,0 +1,39 @@
+use crate::error::{self, ErrorCause, ErrorSource};
+
+/// Specifies the memory ordering of an atomic access to a **`shared`** global, table, struct, or
+/// array, introduced as part of the [shared-everything threads proposal].
+///
+/// [shared-everything threads proposal]: https://github.com/WebAssembly/shared-everything-threads
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum AtomicOrdering {
+    /// **`seqcst`**, sequentially consistent ordering.
+    #[default]
+    SeqCst,
+    /// **`acqrel`**, acquire-release ordering.
+    AcqRel,
+}
+
+impl AtomicOrdering {
+    #[allow(missing_docs)]
+    pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        match input.split_first() {
+            Some((0, input)) => Ok((input, Self::SeqCst)),
+            Some((1, input)) => Ok((input, Self::AcqRel)),
+            bad => Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                error::ErrorKind::OneOf,
+                ErrorCause::InvalidTag(error::InvalidTag::AtomicOrdering(bad.map(|(tag, _)| *tag))),
+            ))),
+        }
+    }
+}
+
+impl core::fmt::Display for AtomicOrdering {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::SeqCst => "seqcst",
+            Self::AcqRel => "acqrel",
+        })
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    error::{self, ErrorCause, ErrorSource},
+    index::Index as _,
+    input::Result,
+    isa::LabelIdx,
+    module::TagIdx,
+    values::VectorIter,
+};
+
+/// A [**`catch`**] clause of a [`try_table`] instruction, introduced as part of the
+/// [exception handling proposal].
+///
+/// [**`catch`**]: https://webassembly.github.io/exception-handling/core/binary/instructions.html#control-instructions
+/// [`try_table`]: crate::isa::ParseInstr::try_table
+/// [exception handling proposal]: https://github.com/WebAssembly/exception-handling
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CatchClause {
+    /// **`catch`** branches to the `label` with the exception's arguments when an exception
+    /// with the given `tag` is thrown.
+    Catch {
+        #[allow(missing_docs)]
+        tag: TagIdx,
+        #[allow(missing_docs)]
+        label: LabelIdx,
+    },
+    /// **`catch_ref`** is like [`CatchClause::Catch`], but also provides an **`exnref`** to the
+    /// caught exception.
+    CatchRef {
+        #[allow(missing_docs)]
+        tag: TagIdx,
+        #[allow(missing_docs)]
+        label: LabelIdx,
+    },
+    /// **`catch_all`** branches to the `label` when any exception is thrown.
+    CatchAll {
+        #[allow(missing_docs)]
+        label: LabelIdx,
+    },
+    /// **`catch_all_ref`** is like [`CatchClause::CatchAll`], but also provides an **`exnref`**
+    /// to the caught exception.
+    CatchAllRef {
+        #[allow(missing_docs)]
+        label: LabelIdx,
+    },
+}
+
+impl CatchClause {
+    /// Gets the label that is branched to when an exception is caught.
+    #[inline]
+    pub const fn label(&self) -> LabelIdx {
+        match self {
+            Self::Catch { label, .. }
+            | Self::CatchRef { label, .. }
+            | Self::CatchAll { label }
+            | Self::CatchAllRef { label } => *label,
+        }
+    }
+
+    /// Gets the tag of the exceptions that are caught, or `None` if all exceptions are caught.
+    #[inline]
+    pub const fn tag(&self) -> Option<TagIdx> {
+        match self {
+            Self::Catch { tag, .. } | Self::CatchRef { tag, .. } => Some(*tag),
+            Self::CatchAll { .. } | Self::CatchAllRef { .. } => None,
+        }
+    }
+
+    #[allow(missing_docs)]
+    pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let (input, kind) = if let Some((first, remaining)) = input.split_first() {
+            (remaining, *first)
+        } else {
+            return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                error::ErrorKind::OneOf,
+                ErrorCause::InvalidTag(error::InvalidTag::CatchClause(None)),
+            )));
+        };
+
+        match kind {
+            0 | 1 => {
+                let (input, tag) = TagIdx::parse(input)?;
+                let (input, label) = LabelIdx::parse(input)?;
+                let clause = if kind == 0 {
+                    Self::Catch { tag, label }
+                } else {
+                    Self::CatchRef { tag, label }
+                };
+
+                Ok((input, clause))
+            }
+            2 | 3 => {
+                let (input, label) = LabelIdx::parse(input)?;
+                let clause = if kind == 2 {
+                    Self::CatchAll { label }
+                } else {
+                    Self::CatchAllRef { label }
+                };
+
+                Ok((input, clause))
+            }
+            _ => Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                error::ErrorKind::OneOf,
+                ErrorCause::InvalidTag(error::InvalidTag::CatchClause(Some(kind))),
+            ))),
+        }
+    }
+}
+
+impl core::fmt::Display for CatchClause {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Catch { tag, label } => write!(f, "(catch {tag} {label})"),
+            Self::CatchRef { tag, label } => write!(f, "(catch_ref {tag} {label})"),
+            Self::CatchAll { label } => write!(f, "(catch_all {label})"),
+            Self::CatchAllRef { label } => write!(f, "(catch_all_ref {label})"),
+        }
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`CatchClause::parse()`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct CatchClauseParser;
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], CatchClause, E> for CatchClauseParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, CatchClause, E> {
+        CatchClause::parse(input)
+    }
+}
+
+/// Parses the [`CatchClause`]s of a [`try_table`] instruction.
+///
+/// [`try_table`]: crate::isa::ParseInstr::try_table
+#[derive(Clone)]
+#[must_use]
+pub struct CatchClauses<'a, E: ErrorSource<'a> = crate::error::Error<'a>> {
+    clauses: VectorIter<'a, CatchClause, E, CatchClauseParser>,
+}
+
+#[allow(missing_docs)]
+impl<'a, E: ErrorSource<'a>> CatchClauses<'a, E> {
+    pub fn with_input(input: &'a [u8]) -> Result<Self, E> {
+        Ok(Self {
+            clauses: VectorIter::with_parsed_length(input, CatchClauseParser)?,
+        })
+    }
+
+    pub fn finish(self) -> crate::Parsed<'a, (), E> {
+        self.clauses.finish().map(|(input, _)| (input, ()))
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> Iterator for CatchClauses<'a, E> {
+    type Item = Result<CatchClause, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.clauses.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.clauses.size_hint()
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> ExactSizeIterator for CatchClauses<'a, E> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.clauses.len()
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> core::fmt::Debug for CatchClauses<'a, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CatchClauses").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,525 @@
+use crate::{
+    error::{ErrorCause, ErrorSource},
+    features::WasmFeatures,
+    isa::{
+        self, AtomicOrdering, LabelIdx, LaneIdx, MemArg, Opcode, ParseInstr, ParseInstrError,
+        Result,
+    },
+    module::{
+        DataIdx, ElemIdx, FieldIdx, FuncIdx, GlobalIdx, LocalIdx, MemIdx, TableIdx, TagIdx, TypeIdx,
+    },
+    storage::{Heap, Vector as _},
+    types::{BlockType, HeapType, RefType},
+    values::{V128ShuffleLanes, F32, F64, V128},
+};
+
+/// Describes why a WebAssembly [constant expression](const_expr) is invalid, or could not be
+/// evaluated by a [`ConstExprEvaluator`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidConstExpr {
+    /// An instruction that is not allowed in constant expressions was encountered.
+    NonConstantInstr(Opcode),
+    /// A `global.get` instruction referred to a global whose value was not provided.
+    UnknownGlobal(GlobalIdx),
+    /// The operands of an instruction were of the wrong type.
+    TypeMismatch(Opcode),
+    /// An instruction expected more operands than were present on the operand stack.
+    StackUnderflow(Opcode),
+    /// The expression did not produce exactly one value.
+    ResultCount(u8),
+}
+
+impl core::fmt::Display for InvalidConstExpr {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::NonConstantInstr(opcode) => {
+                write!(f, "`{opcode}` is not allowed in a constant expression")
+            }
+            Self::UnknownGlobal(index) => {
+                write!(
+                    f,
+                    "value of global {index} in constant expression is not known"
+                )
+            }
+            Self::TypeMismatch(opcode) => {
+                write!(
+                    f,
+                    "operands of `{opcode}` in constant expression have the wrong type"
+                )
+            }
+            Self::StackUnderflow(opcode) => {
+                write!(f, "missing operands for `{opcode}` in constant expression")
+            }
+            Self::ResultCount(count) => write!(
+                f,
+                "constant expression should produce exactly 1 value, but produced {count}"
+            ),
+        }
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidConstExpr {}
+
+struct ConstExprInstr<P> {
+    features: WasmFeatures,
+    parser: P,
+}
+
+fn non_constant<E>(opcode: Opcode) -> Result<(), E> {
+    Err(ParseInstrError::Cause(ErrorCause::ConstExpr(
+        InvalidConstExpr::NonConstantInstr(opcode),
+    )))
+}
+
+macro_rules! const_expr_method {
+    (@delegate $name:ident($($parameter:ident: $parameter_ty:ty),*)) => {
+        #[inline]
+        fn $name(&mut self $(, $parameter: $parameter_ty)*) -> Result<(), E> {
+            self.parser.$name($($parameter),*)
+        }
+    };
+    (@extended $name:ident) => {
+        #[inline]
+        fn $name(&mut self) -> Result<(), E> {
+            if !self.features.contains(WasmFeatures::EXTENDED_CONST) {
+                return Err(ParseInstrError::Cause(ErrorCause::FeatureDisabled(
+                    WasmFeatures::EXTENDED_CONST,
+                )));
+            }
+
+            self.parser.$name()
+        }
+    };
+    (End end) => {
+        const_expr_method!(@delegate end());
+    };
+    (I32Const i32_const { n: i32 }) => {
+        const_expr_method!(@delegate i32_const(n: i32));
+    };
+    (I64Const i64_const { n: i64 }) => {
+        const_expr_method!(@delegate i64_const(n: i64));
+    };
+    (F32Const f32_const { z: F32 }) => {
+        const_expr_method!(@delegate f32_const(z: F32));
+    };
+    (F64Const f64_const { z: F64 }) => {
+        const_expr_method!(@delegate f64_const(z: F64));
+    };
+    (V128Const v128_const { v: V128 }) => {
+        const_expr_method!(@delegate v128_const(v: V128));
+    };
+    (RefNull ref_null { heap_type: HeapType }) => {
+        const_expr_method!(@delegate ref_null(heap_type: HeapType));
+    };
+    (RefFunc ref_func { target: FuncIdx }) => {
+        const_expr_method!(@delegate ref_func(target: FuncIdx));
+    };
+    (GlobalGet global_get { r#global: GlobalIdx }) => {
+        const_expr_method!(@delegate global_get(r#global: GlobalIdx));
+    };
+    (I32Add i32_add) => {
+        const_expr_method!(@extended i32_add);
+    };
+    (I32Sub i32_sub) => {
+        const_expr_method!(@extended i32_sub);
+    };
+    (I32Mul i32_mul) => {
+        const_expr_method!(@extended i32_mul);
+    };
+    (I64Add i64_add) => {
+        const_expr_method!(@extended i64_add);
+    };
+    (I64Sub i64_sub) => {
+        const_expr_method!(@extended i64_sub);
+    };
+    (I64Mul i64_mul) => {
+        const_expr_method!(@extended i64_mul);
+    };
+    (BrTable br_table { targets: BrTableTargets }) => {
+        #[inline]
+        fn br_table(&mut self, targets: &mut isa::BrTableTargets<'a, E>) -> Result<(), E> {
+            let _ = targets;
+            non_constant(Opcode::BrTable)
+        }
+    };
+    (SelectTyped select_typed { types: SelectTypes }) => {
+        #[inline]
+        fn select_typed(&mut self, types: &mut isa::SelectTypes<'a, E>) -> Result<(), E> {
+            let _ = types;
+            non_constant(Opcode::SelectTyped)
+        }
+    };
+    (TryTable try_table { block_type: BlockType, catches: CatchClauses }) => {
+        #[inline]
+        fn try_table(
+            &mut self,
+            block_type: BlockType,
+            catches: &mut isa::CatchClauses<'a, E>,
+        ) -> Result<(), E> {
+            let _ = (block_type, catches);
+            non_constant(Opcode::TryTable)
+        }
+    };
+    (Resume resume { cont_type: TypeIdx, handlers: ResumeHandlers }) => {
+        #[inline]
+        fn resume(
+            &mut self,
+            cont_type: TypeIdx,
+            handlers: &mut isa::ResumeHandlers<'a, E>,
+        ) -> Result<(), E> {
+            let _ = (cont_type, handlers);
+            non_constant(Opcode::Resume)
+        }
+    };
+    (ResumeThrow resume_throw { cont_type: TypeIdx, tag: TagIdx, handlers: ResumeHandlers }) => {
+        #[inline]
+        fn resume_throw(
+            &mut self,
+            cont_type: TypeIdx,
+            tag: TagIdx,
+            handlers: &mut isa::ResumeHandlers<'a, E>,
+        ) -> Result<(), E> {
+            let _ = (cont_type, tag, handlers);
+            non_constant(Opcode::ResumeThrow)
+        }
+    };
+    ($pascal_ident:ident $snake_ident:ident $({ $($field_name:ident: $field_type:ident),+ })?) => {
+        #[inline]
+        fn $snake_ident(&mut self $(, $($field_name: $field_type),+)?) -> Result<(), E> {
+            $($(let _ = $field_name;)+)?
+            non_constant(Opcode::$pascal_ident)
+        }
+    };
+}
+
+macro_rules! const_expr_definitions {
+    ($(
+        $_opcode_case:ident $_proposal:ident $_wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
+    )*) => {
+        $(
+            const_expr_method!($pascal_ident $snake_ident $({ $($field_name: $field_type),+ })?);
+        )*
+    };
+}
+
+impl<'a, P, E> ParseInstr<'a, E> for ConstExprInstr<P>
+where
+    P: ParseInstr<'a, E>,
+    E: ErrorSource<'a>,
+{
+    crate::isa::instr_definitions::all!(const_expr_definitions);
+}
+
+/// Parses a WebAssembly [constant expression], which is an [**`expr`**](isa::expr()) that only
+/// contains constant instructions.
+///
+/// The constant instructions are:
+/// - `i32.const`, `i64.const`, `f32.const`, `f64.const`, and `v128.const`.
+/// - `ref.null` and `ref.func`.
+/// - `global.get`.
+/// - `i32.add`, `i32.sub`, `i32.mul`, `i64.add`, `i64.sub`, and `i64.mul`, introduced as part of
+///   the [extended constant expressions proposal].
+///
+/// # Errors
+///
+/// Returns an error with an [`ErrorCause::ConstExpr`] if any other instruction is encountered.
+/// See the documentation for [`isa::expr()`] for more information.
+///
+/// [constant expression]: https://webassembly.github.io/spec/core/valid/instructions.html#constant-expressions
+/// [extended constant expressions proposal]: https://github.com/WebAssembly/extended-const
+pub fn const_expr<'a, P, E>(input: &'a [u8], parser: P) -> crate::Parsed<'a, P, E>
+where
+    P: ParseInstr<'a, E>,
+    E: ErrorSource<'a>,
+{
+    let instr = ConstExprInstr {
+        features: WasmFeatures::ALL,
+        parser,
+    };
+
+    isa::expr(input, instr).map(|(input, instr)| (input, instr.parser))
+}
+
+/// Parses a WebAssembly [constant expression](const_expr()), failing if any of its instructions
+/// were introduced by a proposal that is not enabled in the given set of `features`.
+///
+/// The **`i32.add`**, **`i32.sub`**, **`i32.mul`**, **`i64.add`**, **`i64.sub`**, and
+/// **`i64.mul`** instructions are only allowed if the
+/// [extended constant expressions proposal](WasmFeatures::EXTENDED_CONST) is enabled.
+///
+/// # Errors
+///
+/// Returns an error with an [`ErrorCause::FeatureDisabled`] if an instruction requires a disabled
+/// proposal. See the documentation for [`const_expr()`] and [`isa::expr_with_features()`] for more
+/// information.
+pub fn const_expr_with_features<'a, P, E>(
+    input: &'a [u8],
+    features: WasmFeatures,
+    parser: P,
+) -> crate::Parsed<'a, P, E>
+where
+    P: ParseInstr<'a, E>,
+    E: ErrorSource<'a>,
+{
+    isa::expr_with_features(input, features, ConstExprInstr { features, parser })
+        .map(|(input, instr)| (input, instr.parser))
+}
+
+/// Checks that the extended constant expression instructions are enabled, without rejecting
+/// instructions that are not allowed in constant expressions.
+struct ExtendedConstCheck {
+    features: WasmFeatures,
+}
+
+macro_rules! extended_const_check_method {
+    (@extended $name:ident) => {
+        #[inline]
+        fn $name(&mut self) -> Result<(), E> {
+            if self.features.contains(WasmFeatures::EXTENDED_CONST) {
+                Ok(())
+            } else {
+                Err(ParseInstrError::Cause(ErrorCause::FeatureDisabled(
+                    WasmFeatures::EXTENDED_CONST,
+                )))
+            }
+        }
+    };
+    ($name:ident($($($parameter:ident: $parameter_ty:ty),+)?)) => {
+        #[inline]
+        fn $name(&mut self $(, $($parameter: $parameter_ty),+)?) -> Result<(), E> {
+            $($(let _ = $parameter;)*)?
+            Ok(())
+        }
+    };
+}
+
+macro_rules! extended_const_check_definitions {
+    (@method I32Add i32_add) => {
+        extended_const_check_method!(@extended i32_add);
+    };
+    (@method I32Sub i32_sub) => {
+        extended_const_check_method!(@extended i32_sub);
+    };
+    (@method I32Mul i32_mul) => {
+        extended_const_check_method!(@extended i32_mul);
+    };
+    (@method I64Add i64_add) => {
+        extended_const_check_method!(@extended i64_add);
+    };
+    (@method I64Sub i64_sub) => {
+        extended_const_check_method!(@extended i64_sub);
+    };
+    (@method I64Mul i64_mul) => {
+        extended_const_check_method!(@extended i64_mul);
+    };
+    (@method $pascal_ident:ident $snake_ident:ident $({ $($field_name:ident: $field_type:ident),+ })?) => {
+        isa::parse_instr::instr_method_declaration!(extended_const_check_method($snake_ident $({ $($field_name: $field_type),+ })?));
+    };
+    ($(
+        $_opcode_case:ident $_proposal:ident $_wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
+    )*) => {
+        $(
+            extended_const_check_definitions!(@method $pascal_ident $snake_ident $({ $($field_name: $field_type),+ })?);
+        )*
+    };
+}
+
+impl<'a, E: ErrorSource<'a>> ParseInstr<'a, E> for ExtendedConstCheck {
+    crate::isa::instr_definitions::all!(extended_const_check_definitions);
+}
+
+/// Checks that the instructions of an initializer expression, such as that of a
+/// [`Global`](crate::module::Global), only use proposals that are enabled in the given set of
+/// `features`.
+///
+/// Unlike [`const_expr_with_features()`], instructions that are not allowed in constant
+/// expressions are not rejected.
+pub(crate) fn init_expr_features<'a, E>(
+    input: &'a [u8],
+    features: WasmFeatures,
+) -> crate::input::Result<(), E>
+where
+    E: ErrorSource<'a>,
+{
+    isa::expr_with_features(input, features, ExtendedConstCheck { features }).map(|_| ())
+}
+
+/// A value produced by a WebAssembly [constant expression](const_expr).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ConstValue {
+    #[allow(missing_docs)]
+    I32(i32),
+    #[allow(missing_docs)]
+    I64(i64),
+    #[allow(missing_docs)]
+    F32(F32),
+    #[allow(missing_docs)]
+    F64(F64),
+    #[allow(missing_docs)]
+    V128(V128),
+    /// A **`null`** reference of the given [`HeapType`].
+    RefNull(HeapType),
+    /// A reference to a function.
+    RefFunc(FuncIdx),
+}
+
+impl ConstValue {
+    /// Gets the value as an `i32`, if it is one.
+    #[inline]
+    pub fn as_i32(&self) -> Option<i32> {
+        if let Self::I32(n) = self {
+            Some(*n)
+        } else {
+            None
+        }
+    }
+
+    /// Gets the value as an `i64`, if it is one.
+    #[inline]
+    pub fn as_i64(&self) -> Option<i64> {
+        if let Self::I64(n) = self {
+            Some(*n)
+        } else {
+            None
+        }
+    }
+}
+
+/// Evaluates a WebAssembly [constant expression](const_expr).
+///
+/// The values of any globals that are referred to by **`global.get`** instructions, such as
+/// imported globals, must be provided. The operand stack is allocated in the given [`Heap`].
+pub struct ConstExprEvaluator<'g, H: Heap> {
+    globals: &'g [ConstValue],
+    stack: H::Vector<ConstValue>,
+}
+
+impl<'g, H: Heap> ConstExprEvaluator<'g, H> {
+    /// Creates a new evaluator, with the values of the globals in the module, indexed by
+    /// [`GlobalIdx`], allocating its operand stack in the given `heap`.
+    pub fn new(globals: &'g [ConstValue], heap: H) -> Self {
+        Self {
+            globals,
+            stack: heap.vector(),
+        }
+    }
+
+    /// Gets the value that the constant expression evaluated to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression did not produce exactly one value.
+    pub fn finish(self) -> core::result::Result<ConstValue, InvalidConstExpr> {
+        match &*self.stack {
+            [value] => Ok(*value),
+            values => Err(InvalidConstExpr::ResultCount(
+                u8::try_from(values.len()).unwrap_or(u8::MAX),
+            )),
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::unnecessary_wraps)]
+    fn push<E>(&mut self, value: ConstValue) -> Result<(), E> {
+        self.stack.push(value);
+        Ok(())
+    }
+
+    fn binary_op<E>(
+        &mut self,
+        opcode: Opcode,
+        op: fn(ConstValue, ConstValue) -> Option<ConstValue>,
+    ) -> Result<(), E> {
+        let error = |reason| ParseInstrError::Cause(ErrorCause::ConstExpr(reason));
+        let y = self
+            .stack
+            .pop()
+            .ok_or_else(|| error(InvalidConstExpr::StackUnderflow(opcode)))?;
+        let x = self
+            .stack
+            .pop()
+            .ok_or_else(|| error(InvalidConstExpr::StackUnderflow(opcode)))?;
+        let result = op(x, y).ok_or_else(|| error(InvalidConstExpr::TypeMismatch(opcode)))?;
+        self.push(result)
+    }
+}
+
+impl<H: Heap> core::fmt::Debug for ConstExprEvaluator<'_, H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConstExprEvaluator")
+            .field("globals", &self.globals)
+            .field("stack", &&*self.stack)
+            .finish()
+    }
+}
+
+macro_rules! evaluator_binary_ops {
+    ($($name:ident => $opcode:ident($case:ident::$method:ident);)*) => {$(
+        fn $name(&mut self) -> Result<(), E> {
+            self.binary_op(Opcode::$opcode, |x, y| match (x, y) {
+                (ConstValue::$case(x), ConstValue::$case(y)) => Some(ConstValue::$case(x.$method(y))),
+                _ => None,
+            })
+        }
+    )*};
+}
+
+impl<'a, E: ErrorSource<'a>, H: Heap> ParseInstr<'a, E> for ConstExprEvaluator<'_, H> {
+    #[inline]
+    fn end(&mut self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn i32_const(&mut self, n: i32) -> Result<(), E> {
+        self.push(ConstValue::I32(n))
+    }
+
+    fn i64_const(&mut self, n: i64) -> Result<(), E> {
+        self.push(ConstValue::I64(n))
+    }
+
+    fn f32_const(&mut self, z: F32) -> Result<(), E> {
+        self.push(ConstValue::F32(z))
+    }
+
+    fn f64_const(&mut self, z: F64) -> Result<(), E> {
+        self.push(ConstValue::F64(z))
+    }
+
+    fn v128_const(&mut self, v: V128) -> Result<(), E> {
+        self.push(ConstValue::V128(v))
+    }
+
+    fn ref_null(&mut self, heap_type: HeapType) -> Result<(), E> {
+        self.push(ConstValue::RefNull(heap_type))
+    }
+
+    fn ref_func(&mut self, target: FuncIdx) -> Result<(), E> {
+        self.push(ConstValue::RefFunc(target))
+    }
+
+    fn global_get(&mut self, r#global: GlobalIdx) -> Result<(), E> {
+        let value = self
+            .globals
+            .get(nom::ToUsize::to_usize(&r#global))
+            .copied()
+            .ok_or(ParseInstrError::Cause(ErrorCause::ConstExpr(
+                InvalidConstExpr::UnknownGlobal(r#global),
+            )))?;
+
+        self.push(value)
+    }
+
+    evaluator_binary_ops! {
+        i32_add => I32Add(I32::wrapping_add);
+        i32_sub => I32Sub(I32::wrapping_sub);
+        i32_mul => I32Mul(I32::wrapping_mul);
+        i64_add => I64Add(I64::wrapping_add);
+        i64_sub => I64Sub(I64::wrapping_sub);
+        i64_mul => I64Mul(I64::wrapping_mul);
+    }
+}
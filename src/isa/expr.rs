@@ -1,8 +1,11 @@
 use crate::{
     error::{ErrorCause, ErrorSource},
-    isa::{self, LabelIdx, LaneIdx, MemArg, ParseInstr, Result},
-    module::{DataIdx, ElemIdx, FuncIdx, GlobalIdx, LocalIdx, MemIdx, TableIdx, TagIdx, TypeIdx},
-    types::{BlockType, RefType},
+    input::AsInput,
+    isa::{self, AtomicOrdering, LabelIdx, LaneIdx, MemArg, ParseInstr, Result},
+    module::{
+        DataIdx, ElemIdx, FieldIdx, FuncIdx, GlobalIdx, LocalIdx, MemIdx, TableIdx, TagIdx, TypeIdx,
+    },
+    types::{BlockType, HeapType, RefType},
     values::{V128ShuffleLanes, F32, F64, V128},
 };
 
@@ -43,8 +46,23 @@ where
 }
 
 macro_rules! update_block_count {
-    ($self:ident @ block | r#loop | r#if | r#try) => {
-        if let Some(level) = self.block_nesting.checked_add(1) {
+    ($self:ident @ block) => {
+        update_block_count!($self @ nested);
+    };
+    ($self:ident @ r#loop) => {
+        update_block_count!($self @ nested);
+    };
+    ($self:ident @ r#if) => {
+        update_block_count!($self @ nested);
+    };
+    ($self:ident @ r#try) => {
+        update_block_count!($self @ nested);
+    };
+    ($self:ident @ try_table) => {
+        update_block_count!($self @ nested);
+    };
+    ($self:ident @ nested) => {
+        if let Some(level) = $self.block_nesting.checked_add(1) {
             $self.block_nesting = level;
         } else {
             return Err(isa::ParseInstrError::Cause(ErrorCause::Expr(
@@ -80,7 +98,7 @@ macro_rules! parse_expr_method {
 
 macro_rules! parse_expr_definitions {
     ($(
-        $opcode_case:ident $wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
+        $opcode_case:ident $_proposal:ident $wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
     )*) => {
         $(
             isa::parse_instr::instr_method_declaration!(parse_expr_method($snake_ident $({ $($field_name: $field_type),+ })?));
@@ -101,10 +119,25 @@ where
 ///
 /// [WebAssembly expression]: https://webassembly.github.io/spec/core/binary/instructions.html#expressions
 /// [**`end`**]: ParseInstr::end
-pub fn expr<'a, P, E>(mut input: &'a [u8], parser: P) -> crate::Parsed<'a, P, E>
+pub fn expr<'a, P, E>(input: &'a [u8], parser: P) -> crate::Parsed<'a, P, E>
 where
     P: ParseInstr<'a, E>,
     E: ErrorSource<'a>,
+{
+    expr_inspect(input, parser, |_, _| Ok(()))
+}
+
+/// Parses a WebAssembly [**`expr`**](expr), calling `inspect` with the remaining input before each
+/// instruction is parsed.
+pub(in crate::isa) fn expr_inspect<'a, P, E, F>(
+    mut input: &'a [u8],
+    parser: P,
+    mut inspect: F,
+) -> crate::Parsed<'a, P, E>
+where
+    P: ParseInstr<'a, E>,
+    E: ErrorSource<'a>,
+    F: FnMut(&'a [u8], &mut P) -> crate::input::Result<(), E>,
 {
     let mut state = ParseExprInstr {
         block_nesting: 1, // WASM expressions start with an implicit `block`
@@ -113,8 +146,103 @@ where
     };
 
     while state.block_nesting > 0 {
+        inspect(input, &mut state.parser)?;
         input = isa::instr(input, &mut state)?.0;
     }
 
     Ok((input, state.parser))
 }
+
+/// Represents a WebAssembly [**`expr`**](expr) whose instructions have not yet been parsed.
+///
+/// This is used for the initializer expressions of tables, globals, and segments, allowing the
+/// instructions to be processed later by any [`ParseInstr`] implementation.
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct Expr<'a> {
+    instructions: &'a [u8],
+}
+
+impl<'a> Expr<'a> {
+    /// Parses an expression, returning its bytes up to and including the final [**`end`**]
+    /// instruction.
+    ///
+    /// # Errors
+    ///
+    /// See the documentation for [`expr()`] for more information.
+    ///
+    /// [**`end`**]: ParseInstr::end
+    pub fn parse<E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let (remaining, ()) = expr(input, ())?;
+        let instructions = &input[..input.len() - remaining.len()];
+        Ok((remaining, Self { instructions }))
+    }
+
+    /// Parses the instructions of the expression with the given [`ParseInstr`] implementation.
+    pub fn parse_with<P, E>(&self, parser: P) -> crate::input::Result<P, E>
+    where
+        P: ParseInstr<'a, E>,
+        E: ErrorSource<'a>,
+    {
+        expr(self.instructions, parser).map(|(_, parser)| parser)
+    }
+
+    /// Evaluates this expression as a [constant expression](isa::const_expr()), using the given
+    /// values for any globals referred to by [**`global.get`**] instructions. The operand stack
+    /// is allocated in the given [`Heap`](crate::storage::Heap).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression is not a valid constant expression, or if it did not
+    /// produce exactly one value.
+    ///
+    /// [**`global.get`**]: ParseInstr::global_get
+    pub fn evaluate<E, H>(
+        &self,
+        globals: &[isa::ConstValue],
+        heap: H,
+    ) -> crate::input::Result<isa::ConstValue, E>
+    where
+        E: ErrorSource<'a>,
+        H: crate::storage::Heap,
+    {
+        let (_, evaluator) = isa::const_expr(
+            self.instructions,
+            isa::ConstExprEvaluator::new(globals, heap),
+        )?;
+
+        evaluator.finish().map_err(|reason| {
+            nom::Err::Failure(E::from_error_kind_and_cause(
+                self.instructions,
+                crate::error::ErrorKind::Verify,
+                ErrorCause::ConstExpr(reason),
+            ))
+        })
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`Expr::parse()`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct ExprParser;
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], Expr<'a>, E> for ExprParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, Expr<'a>, E> {
+        Expr::parse(input)
+    }
+}
+
+impl<'a> AsInput<'a> for Expr<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.instructions
+    }
+}
+
+impl core::fmt::Debug for Expr<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Expr")
+            .field(&crate::hex::Bytes(self.instructions))
+            .finish()
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{
+    error::{ErrorCause, ErrorSource},
+    features::WasmFeatures,
+    isa::{
+        self, AtomicOrdering, BrTableTargets, CatchClauses, LabelIdx, LaneIdx, MemArg, Opcode,
+        ParseInstr, ParseInstrError, Result, ResumeHandlers, SelectTypes,
+    },
+    module::{
+        DataIdx, ElemIdx, FieldIdx, FuncIdx, GlobalIdx, LocalIdx, MemIdx, TableIdx, TagIdx, TypeIdx,
+    },
+    types::{BlockType, HeapType, RefType},
+    values::{V128ShuffleLanes, F32, F64, V128},
+};
+
+/// Gets the proposals required by an immediate argument of an instruction.
+trait FieldFeatures {
+    fn field_features(&self) -> WasmFeatures {
+        WasmFeatures::EMPTY
+    }
+}
+
+macro_rules! no_field_features {
+    ($($field_type:ty),*) => {$(
+        impl FieldFeatures for $field_type {}
+    )*};
+}
+
+no_field_features!(
+    AtomicOrdering,
+    LabelIdx,
+    LaneIdx,
+    DataIdx,
+    ElemIdx,
+    FieldIdx,
+    FuncIdx,
+    GlobalIdx,
+    LocalIdx,
+    TagIdx,
+    TypeIdx,
+    V128ShuffleLanes,
+    F32,
+    F64,
+    V128,
+    i32,
+    i64,
+    u32
+);
+
+impl FieldFeatures for MemIdx {
+    fn field_features(&self) -> WasmFeatures {
+        if self.0 == 0 {
+            WasmFeatures::EMPTY
+        } else {
+            WasmFeatures::MULTI_MEMORY
+        }
+    }
+}
+
+impl FieldFeatures for MemArg {
+    #[inline]
+    fn field_features(&self) -> WasmFeatures {
+        self.memory.field_features()
+    }
+}
+
+impl FieldFeatures for TableIdx {
+    fn field_features(&self) -> WasmFeatures {
+        // Multiple tables were introduced in the reference types proposal
+        if self.0 == 0 {
+            WasmFeatures::EMPTY
+        } else {
+            WasmFeatures::REFERENCE_TYPES
+        }
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> FieldFeatures for BrTableTargets<'a, E> {}
+
+impl<'a, E: ErrorSource<'a>> FieldFeatures for CatchClauses<'a, E> {}
+
+impl<'a, E: ErrorSource<'a>> FieldFeatures for ResumeHandlers<'a, E> {}
+
+impl FieldFeatures for BlockType {
+    #[inline]
+    fn field_features(&self) -> WasmFeatures {
+        self.required_features()
+    }
+}
+
+impl FieldFeatures for HeapType {
+    #[inline]
+    fn field_features(&self) -> WasmFeatures {
+        self.required_features()
+    }
+}
+
+impl FieldFeatures for RefType {
+    #[inline]
+    fn field_features(&self) -> WasmFeatures {
+        self.required_features()
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> FieldFeatures for SelectTypes<'a, E> {
+    fn field_features(&self) -> WasmFeatures {
+        // Types that could not be parsed are reported when the actual parser consumes them
+        self.clone()
+            .filter_map(core::result::Result::ok)
+            .fold(WasmFeatures::EMPTY, |features, value_type| {
+                features | value_type.required_features()
+            })
+    }
+}
+
+struct FeatureCheckInstr<P> {
+    features: WasmFeatures,
+    parser: P,
+}
+
+macro_rules! feature_check_method {
+    ($name:ident($($parameter:ident: $parameter_ty:ty),*)) => {
+        #[inline]
+        fn $name(&mut self $(, $parameter: $parameter_ty)*) -> Result<(), E> {
+            let required = WasmFeatures::EMPTY $(.union($parameter.field_features()))*;
+            let disabled = required.difference(self.features);
+            if !disabled.is_empty() {
+                return Err(ParseInstrError::Cause(ErrorCause::FeatureDisabled(disabled)));
+            }
+
+            self.parser.$name($($parameter),*)
+        }
+    };
+}
+
+macro_rules! feature_check_definitions {
+    ($(
+        $_opcode_case:ident $_proposal:ident $_wasm_name:literal $_pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
+    )*) => {
+        $(
+            isa::parse_instr::instr_method_declaration!(feature_check_method($snake_ident $({ $($field_name: $field_type),+ })?));
+        )*
+    };
+}
+
+impl<'a, P, E> ParseInstr<'a, E> for FeatureCheckInstr<P>
+where
+    P: ParseInstr<'a, E>,
+    E: ErrorSource<'a>,
+{
+    crate::isa::instr_definitions::all!(feature_check_definitions);
+}
+
+fn check_opcode<'a, E: ErrorSource<'a>>(
+    input: &'a [u8],
+    features: WasmFeatures,
+) -> crate::input::Result<(), E> {
+    let (_, opcode) = Opcode::parse(input)?;
+    features.check(input, opcode.proposal())
+}
+
+/// Parses a WebAssembly [instruction](isa::instr()), failing if it was introduced by a proposal
+/// that is not enabled in the given set of `features`.
+///
+/// The types in the instruction's immediate arguments, such as the [`BlockType`] of a **`block`**
+/// instruction, are also checked, as are references to memories or tables other than the first,
+/// which require the [multi-memory](WasmFeatures::MULTI_MEMORY) or
+/// [reference types](WasmFeatures::REFERENCE_TYPES) proposals.
+///
+/// # Errors
+///
+/// Returns an error with an [`ErrorCause::FeatureDisabled`] if the instruction requires a disabled
+/// proposal. See the documentation for [`isa::instr()`] for more information.
+pub fn instr_with_features<'a, P, E>(
+    input: &'a [u8],
+    features: WasmFeatures,
+    parser: P,
+) -> crate::Parsed<'a, P, E>
+where
+    P: ParseInstr<'a, E>,
+    E: ErrorSource<'a>,
+{
+    check_opcode(input, features)?;
+    isa::instr(input, FeatureCheckInstr { features, parser })
+        .map(|(input, check)| (input, check.parser))
+}
+
+/// Parses a WebAssembly [**`expr`**](isa::expr()), failing if any of its instructions were
+/// introduced by a proposal that is not enabled in the given set of `features`.
+///
+/// See the documentation for [`instr_with_features()`] for more information.
+///
+/// # Errors
+///
+/// Returns an error with an [`ErrorCause::FeatureDisabled`] if an instruction requires a disabled
+/// proposal. See the documentation for [`isa::expr()`] for more information.
+pub fn expr_with_features<'a, P, E>(
+    input: &'a [u8],
+    features: WasmFeatures,
+    parser: P,
+) -> crate::Parsed<'a, P, E>
+where
+    P: ParseInstr<'a, E>,
+    E: ErrorSource<'a>,
+{
+    isa::expr::expr_inspect(input, FeatureCheckInstr { features, parser }, |input, _| {
+        check_opcode(input, features)
+    })
+    .map(|(input, check)| (input, check.parser))
+}
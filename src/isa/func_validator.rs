@@ -0,0 +1,1686 @@
+use crate::{
+    error::{ErrorCause, ErrorKind, ErrorSource},
+    features::WasmFeatures,
+    isa::{self, Align, LabelIdx, LaneIdx, MemArg, Opcode, ParseInstr, ParseInstrError, Result},
+    module::{
+        DataIdx, ElemIdx, FuncIdx, GlobalIdx, LocalIdx, Locals, LocalsParser, MemIdx, TableIdx,
+        TypeIdx,
+    },
+    storage::{Heap, Vector as _},
+    types::{
+        AbstractHeapType, BlockType, GlobalType, HeapType, IdxType, MemType, Mutability, RefType,
+        Sharing, TableType, ValType,
+    },
+    values::V128ShuffleLanes,
+};
+use nom::ToUsize;
+
+/// Describes why a function body was rejected by a [`FuncValidator`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidFuncBody {
+    /// The operands of an instruction, or the values produced by a block, were of the wrong type.
+    TypeMismatch,
+    /// An instruction expected more operands than were present on the operand stack.
+    StackUnderflow,
+    /// A block produced more values than its type specifies.
+    ValuesRemaining,
+    /// An **`else`** instruction did not follow an **`if`** block, or an **`end`** instruction
+    /// did not close a block.
+    UnmatchedBlock,
+    #[allow(missing_docs)]
+    UnknownLabel(LabelIdx),
+    #[allow(missing_docs)]
+    UnknownLocal(LocalIdx),
+    /// A local variable with a non-defaultable type, such as a non-nullable reference, was read
+    /// before a value was assigned to it.
+    UninitializedLocal(LocalIdx),
+    #[allow(missing_docs)]
+    UnknownGlobal(GlobalIdx),
+    /// A **`global.set`** instruction referred to a global that is not mutable.
+    ImmutableGlobal(GlobalIdx),
+    #[allow(missing_docs)]
+    UnknownFunc(FuncIdx),
+    /// A **`ref.func`** instruction referred to a function that was not
+    /// [declared](ModuleContext::is_func_declared).
+    UndeclaredFuncRef(FuncIdx),
+    /// A type index was out of bounds, or did not refer to a function type.
+    UnknownType(TypeIdx),
+    #[allow(missing_docs)]
+    UnknownTable(TableIdx),
+    #[allow(missing_docs)]
+    UnknownMemory(MemIdx),
+    #[allow(missing_docs)]
+    UnknownElem(ElemIdx),
+    /// A data segment index was out of bounds, or the module does not have a *data count
+    /// section*.
+    UnknownData(DataIdx),
+    /// The alignment of a [`MemArg`] was larger than the natural alignment of the memory access.
+    Alignment(Align),
+    /// A lane index was out of bounds for the vector shape of the instruction.
+    LaneIndex(LaneIdx),
+    /// The instruction was introduced by a proposal that is not supported by the
+    /// [`FuncValidator`].
+    Unsupported(Opcode),
+}
+
+impl core::fmt::Display for InvalidFuncBody {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::TypeMismatch => f.write_str("type mismatch"),
+            Self::StackUnderflow => f.write_str("missing operands on the operand stack"),
+            Self::ValuesRemaining => f.write_str("values remaining on the operand stack"),
+            Self::UnmatchedBlock => f.write_str("instruction does not match an enclosing block"),
+            Self::UnknownLabel(label) => write!(f, "unknown label {label}"),
+            Self::UnknownLocal(local) => write!(f, "unknown local {local}"),
+            Self::UninitializedLocal(local) => {
+                write!(f, "local {local} was used before it was initialized")
+            }
+            Self::UnknownGlobal(global) => write!(f, "unknown global {global}"),
+            Self::ImmutableGlobal(global) => write!(f, "global {global} is immutable"),
+            Self::UnknownFunc(func) => write!(f, "unknown function {func}"),
+            Self::UndeclaredFuncRef(func) => {
+                write!(f, "function {func} was not declared as referenceable")
+            }
+            Self::UnknownType(index) => write!(f, "type {index} is not a function type"),
+            Self::UnknownTable(table) => write!(f, "unknown table {table}"),
+            Self::UnknownMemory(memory) => write!(f, "unknown memory {memory}"),
+            Self::UnknownElem(segment) => write!(f, "unknown element segment {segment}"),
+            Self::UnknownData(segment) => write!(f, "unknown data segment {segment}"),
+            Self::Alignment(align) => write!(
+                f,
+                "alignment of {align} bytes is larger than the natural alignment"
+            ),
+            Self::LaneIndex(lane) => write!(f, "lane index {lane} is out of bounds"),
+            Self::Unsupported(opcode) => write!(f, "validation of {opcode} is not supported"),
+        }
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidFuncBody {}
+
+/// Provides the types of the definitions in a WebAssembly module that the instructions checked
+/// by a [`FuncValidator`] refer to.
+///
+/// Methods that take an index return `None` if the index is out of bounds.
+pub trait ModuleContext {
+    /// Gets the parameter types of the function type at the given index, or `None` if the index
+    /// does not refer to a function type.
+    fn type_parameters(&self, index: TypeIdx) -> Option<&[ValType]>;
+
+    /// Gets the result types of the function type at the given index, or `None` if the index does
+    /// not refer to a function type.
+    fn type_results(&self, index: TypeIdx) -> Option<&[ValType]>;
+
+    /// Returns `true` if the index refers to a type defined in the module, such as the type
+    /// referred to by a **`ref.null`** instruction.
+    ///
+    /// The default implementation only recognizes function types.
+    fn is_type_defined(&self, index: TypeIdx) -> bool {
+        self.type_parameters(index).is_some()
+    }
+
+    /// Gets the index of the function type of a function, including imported functions.
+    fn func(&self, index: FuncIdx) -> Option<TypeIdx>;
+
+    /// Returns `true` if the function can be referred to by a **`ref.func`** instruction.
+    ///
+    /// A function is declared if it is referred to outside of the module's function bodies, such
+    /// as in an element segment, an export, or a global initializer.
+    fn is_func_declared(&self, index: FuncIdx) -> bool;
+
+    /// Gets the type of a table, including imported tables.
+    fn table(&self, index: TableIdx) -> Option<TableType>;
+
+    /// Gets the type of a linear memory, including imported memories.
+    fn memory(&self, index: MemIdx) -> Option<MemType>;
+
+    /// Gets the type of a global, including imported globals.
+    fn global(&self, index: GlobalIdx) -> Option<GlobalType>;
+
+    /// Gets the type of the references in an element segment.
+    fn elem(&self, index: ElemIdx) -> Option<RefType>;
+
+    /// Gets the number of data segments specified by the
+    /// [*data count section*](crate::module::data_count_sec), or `None` if the module does not have
+    /// one.
+    fn data_count(&self) -> Option<u32>;
+}
+
+type Checked<T = ()> = core::result::Result<T, InvalidFuncBody>;
+
+/// A sequence of value types, such as the parameters or results of a block.
+#[derive(Clone, Copy)]
+enum Types<'t> {
+    Slice(&'t [ValType]),
+    Single(ValType),
+}
+
+impl<'t> Types<'t> {
+    const EMPTY: Self = Self::Slice(&[]);
+
+    fn len(self) -> usize {
+        match self {
+            Self::Slice(types) => types.len(),
+            Self::Single(_) => 1,
+        }
+    }
+
+    fn get(self, index: usize) -> ValType {
+        match self {
+            Self::Slice(types) => types[index],
+            Self::Single(value_type) => value_type,
+        }
+    }
+
+    fn iter(self) -> impl DoubleEndedIterator<Item = ValType> + 't {
+        (0..self.len()).map(move |index| self.get(index))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum FrameKind {
+    Block,
+    Loop,
+    If,
+    Else,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ControlFrame {
+    kind: FrameKind,
+    block_type: BlockType,
+    /// The height of the operand stack when the block was entered.
+    height: usize,
+    /// The number of initialized locals when the block was entered.
+    inits: usize,
+    unreachable: bool,
+}
+
+/// Checks that the instructions in a WebAssembly function body are well-typed, following the
+/// [validation algorithm] in the WebAssembly specification.
+///
+/// A [`FuncValidator`] keeps an operand stack and a control stack of [`ValType`]s, which are
+/// allocated in the given [`Heap`] and reused for each function body that is validated. The
+/// types of the functions, tables, memories, globals, and segments that instructions refer to
+/// are obtained from a [`ModuleContext`].
+///
+/// Function bodies are validated with [`FuncBody::validate()`]. Instructions introduced by the
+/// following proposals are supported:
+/// - [sign extension operators](crate::features::WasmFeatures::SIGN_EXT)
+/// - [non-trapping float-to-int conversions](crate::features::WasmFeatures::NONTRAPPING_FPTOINT)
+/// - [multi-value](crate::features::WasmFeatures::MULTI_VALUE)
+/// - [bulk memory operations](crate::features::WasmFeatures::BULK_MEMORY)
+/// - [reference types](crate::features::WasmFeatures::REFERENCE_TYPES)
+/// - [fixed-width SIMD](crate::features::WasmFeatures::SIMD128) and
+///   [relaxed SIMD](crate::features::WasmFeatures::RELAXED_SIMD)
+/// - [tail calls](crate::features::WasmFeatures::TAIL_CALL)
+/// - [64-bit memories and tables](crate::features::WasmFeatures::MEMORY64)
+///
+/// The **`ref.eq`** instruction from the [garbage collection](crate::features::WasmFeatures::GC)
+/// proposal is also supported. Function bodies containing any other instruction, such as those
+/// introduced by the [threads](crate::features::WasmFeatures::ATOMICS) or
+/// [exception handling](crate::features::WasmFeatures::EXCEPTION_HANDLING) proposals, are rejected
+/// with an [`InvalidFuncBody::Unsupported`] error.
+///
+/// [validation algorithm]: https://webassembly.github.io/spec/core/appendix/algorithm.html
+/// [`FuncBody::validate()`]: crate::module::FuncBody::validate
+pub struct FuncValidator<'c, C: ModuleContext + ?Sized, H: Heap> {
+    context: &'c C,
+    /// Run-length encoded types of the function's parameters and local variables, each stored
+    /// with the index one past the last local variable of that type.
+    locals: H::Vector<(u32, ValType)>,
+    parameter_count: u32,
+    /// Contains `None` for values of an unknown type, which are produced in unreachable code.
+    operands: H::Vector<Option<ValType>>,
+    frames: H::Vector<ControlFrame>,
+    /// Non-defaultable local variables that have been assigned a value.
+    inits: H::Vector<LocalIdx>,
+    offset: u32,
+}
+
+impl<'c, C: ModuleContext + ?Sized, H: Heap> FuncValidator<'c, C, H> {
+    /// Creates a new [`FuncValidator`] for function bodies in the module described by the
+    /// `context`, allocating its stacks in the given `heap`.
+    pub fn new(context: &'c C, heap: H) -> Self {
+        Self {
+            context,
+            locals: heap.vector(),
+            parameter_count: 0,
+            operands: heap.vector(),
+            frames: heap.vector(),
+            inits: heap.vector(),
+            offset: 0,
+        }
+    }
+
+    /// Gets the offset of the last instruction that was validated, relative to the start of the
+    /// function body's contents.
+    #[inline]
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    fn begin(&mut self, signature: TypeIdx) -> Checked {
+        self.locals.clear();
+        self.operands.clear();
+        self.frames.clear();
+        self.inits.clear();
+        self.offset = 0;
+
+        let parameters = self.type_parameters(signature)?;
+        let _ = self.type_results(signature)?;
+        for (index, value_type) in (1u32..).zip(parameters) {
+            self.locals.push((index, *value_type));
+        }
+
+        self.parameter_count = parameters.len().try_into().unwrap_or(u32::MAX);
+        self.push_frame(FrameKind::Block, BlockType::Index(signature), Types::EMPTY);
+        Ok(())
+    }
+
+    fn define_locals(&mut self, count: u32, value_type: ValType) -> Option<()> {
+        let start = self.locals.last().map_or(0, |(end, _)| *end);
+        let end = start.checked_add(count)?;
+        if count > 0 {
+            self.locals.push((end, value_type));
+        }
+
+        Some(())
+    }
+
+    fn check<E>(&mut self, f: impl FnOnce(&mut Self) -> Checked) -> Result<(), E> {
+        report(f(self))
+    }
+
+    fn type_parameters(&self, index: TypeIdx) -> Checked<&'c [ValType]> {
+        let context: &'c C = self.context;
+        context
+            .type_parameters(index)
+            .ok_or(InvalidFuncBody::UnknownType(index))
+    }
+
+    fn type_results(&self, index: TypeIdx) -> Checked<&'c [ValType]> {
+        let context: &'c C = self.context;
+        context
+            .type_results(index)
+            .ok_or(InvalidFuncBody::UnknownType(index))
+    }
+
+    fn block_parameters(&self, block_type: BlockType) -> Checked<Types<'c>> {
+        match block_type {
+            BlockType::Index(index) => self.type_parameters(index).map(Types::Slice),
+            _ => Ok(Types::EMPTY),
+        }
+    }
+
+    fn block_results(&self, block_type: BlockType) -> Checked<Types<'c>> {
+        match block_type {
+            BlockType::Empty => Ok(Types::EMPTY),
+            BlockType::Index(index) => self.type_results(index).map(Types::Slice),
+            BlockType::Inline(value_type) => Ok(Types::Single(value_type)),
+        }
+    }
+
+    fn is_func_type(&self, index: TypeIdx) -> bool {
+        self.context.type_parameters(index).is_some()
+    }
+
+    fn heap_type_matches(&self, actual: HeapType, expected: HeapType) -> bool {
+        match (actual, expected) {
+            _ if actual == expected => true,
+            (
+                HeapType::Abstract {
+                    share: actual_share,
+                    abstract_type: actual,
+                },
+                HeapType::Abstract {
+                    share: expected_share,
+                    abstract_type: expected,
+                },
+            ) => actual_share == expected_share && abstract_heap_type_matches(actual, expected),
+            (
+                HeapType::Index(index),
+                HeapType::Abstract {
+                    share: Sharing::Unshared,
+                    abstract_type: AbstractHeapType::Func,
+                },
+            )
+            | (
+                HeapType::Abstract {
+                    share: Sharing::Unshared,
+                    abstract_type: AbstractHeapType::NoFunc,
+                },
+                HeapType::Index(index),
+            ) => self.is_func_type(index),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the `actual` type is a subtype of the `expected` type.
+    fn matches(&self, actual: ValType, expected: ValType) -> bool {
+        match (actual, expected) {
+            (ValType::Ref(actual), ValType::Ref(expected)) => {
+                (expected.nullable || !actual.nullable)
+                    && self.heap_type_matches(actual.heap_type, expected.heap_type)
+            }
+            _ => actual == expected,
+        }
+    }
+
+    fn frame_bounds(&self) -> (usize, bool) {
+        self.frames
+            .last()
+            .map_or((0, false), |frame| (frame.height, frame.unreachable))
+    }
+
+    #[inline]
+    fn push(&mut self, value_type: ValType) {
+        self.operands.push(Some(value_type));
+    }
+
+    fn push_types(&mut self, types: Types<'_>) {
+        for value_type in types.iter() {
+            self.push(value_type);
+        }
+    }
+
+    fn pop(&mut self) -> Checked<Option<ValType>> {
+        let (height, unreachable) = self.frame_bounds();
+        if self.operands.len() > height {
+            Ok(self.operands.pop().flatten())
+        } else if unreachable {
+            Ok(None)
+        } else {
+            Err(InvalidFuncBody::StackUnderflow)
+        }
+    }
+
+    fn pop_expected(&mut self, expected: ValType) -> Checked {
+        match self.pop()? {
+            Some(actual) if !self.matches(actual, expected) => Err(InvalidFuncBody::TypeMismatch),
+            _ => Ok(()),
+        }
+    }
+
+    fn pop_types(&mut self, types: Types<'_>) -> Checked {
+        for expected in types.iter().rev() {
+            self.pop_expected(expected)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the values at the top of the operand stack match the `types`, without popping
+    /// them.
+    fn peek_types(&self, types: Types<'_>) -> Checked {
+        let (height, unreachable) = self.frame_bounds();
+        let mut values = self.operands[height..].iter().rev();
+        for expected in types.iter().rev() {
+            match values.next() {
+                Some(Some(actual)) if !self.matches(*actual, expected) => {
+                    return Err(InvalidFuncBody::TypeMismatch)
+                }
+                Some(_) => (),
+                None if unreachable => break,
+                None => return Err(InvalidFuncBody::StackUnderflow),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn set_unreachable(&mut self) {
+        if let Some(frame) = self.frames.last_mut() {
+            while self.operands.len() > frame.height {
+                self.operands.pop();
+            }
+
+            frame.unreachable = true;
+        }
+    }
+
+    fn push_frame(&mut self, kind: FrameKind, block_type: BlockType, parameters: Types<'_>) {
+        self.frames.push(ControlFrame {
+            kind,
+            block_type,
+            height: self.operands.len(),
+            inits: self.inits.len(),
+            unreachable: false,
+        });
+
+        self.push_types(parameters);
+    }
+
+    fn enter_block(&mut self, kind: FrameKind, block_type: BlockType) -> Checked {
+        let parameters = self.block_parameters(block_type)?;
+        let _ = self.block_results(block_type)?;
+        self.pop_types(parameters)?;
+        self.push_frame(kind, block_type, parameters);
+        Ok(())
+    }
+
+    fn pop_frame(&mut self) -> Checked<ControlFrame> {
+        let frame = *self.frames.last().ok_or(InvalidFuncBody::UnmatchedBlock)?;
+        self.pop_types(self.block_results(frame.block_type)?)?;
+
+        if self.operands.len() != frame.height {
+            return Err(InvalidFuncBody::ValuesRemaining);
+        }
+
+        while self.inits.len() > frame.inits {
+            self.inits.pop();
+        }
+
+        self.frames.pop();
+        Ok(frame)
+    }
+
+    fn else_block(&mut self) -> Checked {
+        match self.frames.last() {
+            Some(frame) if frame.kind == FrameKind::If => (),
+            _ => return Err(InvalidFuncBody::UnmatchedBlock),
+        }
+
+        let frame = self.pop_frame()?;
+        let parameters = self.block_parameters(frame.block_type)?;
+        self.push_frame(FrameKind::Else, frame.block_type, parameters);
+        Ok(())
+    }
+
+    fn end_block(&mut self) -> Checked {
+        let frame = self.pop_frame()?;
+        let results = self.block_results(frame.block_type)?;
+
+        if frame.kind == FrameKind::If {
+            // An `if` without an `else` passes its parameters through as its results
+            let parameters = self.block_parameters(frame.block_type)?;
+            if parameters.len() != results.len()
+                || parameters
+                    .iter()
+                    .zip(results.iter())
+                    .any(|(parameter, result)| !self.matches(parameter, result))
+            {
+                return Err(InvalidFuncBody::TypeMismatch);
+            }
+        }
+
+        self.push_types(results);
+        Ok(())
+    }
+
+    fn label_types(&self, label: LabelIdx) -> Checked<Types<'c>> {
+        let frame = self
+            .frames
+            .len()
+            .checked_sub(1)
+            .and_then(|top| top.checked_sub(label.to_usize()))
+            .and_then(|index| self.frames.get(index))
+            .ok_or(InvalidFuncBody::UnknownLabel(label))?;
+
+        match frame.kind {
+            FrameKind::Loop => self.block_parameters(frame.block_type),
+            _ => self.block_results(frame.block_type),
+        }
+    }
+
+    fn return_types(&self) -> Checked<Types<'c>> {
+        let frame = self.frames.first().ok_or(InvalidFuncBody::UnmatchedBlock)?;
+        self.block_results(frame.block_type)
+    }
+
+    fn operator(&mut self, parameters: &[ValType], results: &[ValType]) -> Checked {
+        self.pop_types(Types::Slice(parameters))?;
+        self.push_types(Types::Slice(results));
+        Ok(())
+    }
+
+    fn select(&mut self) -> Checked {
+        self.pop_expected(ValType::I32)?;
+        let first = self.pop()?;
+        let second = self.pop()?;
+
+        // An untyped `select` can only be used with numeric and vector types
+        if matches!(first, Some(ValType::Ref(_))) || matches!(second, Some(ValType::Ref(_))) {
+            return Err(InvalidFuncBody::TypeMismatch);
+        }
+
+        match (first, second) {
+            (Some(first), Some(second)) if first != second => Err(InvalidFuncBody::TypeMismatch),
+            (Some(value_type), _) | (_, Some(value_type)) => {
+                self.push(value_type);
+                Ok(())
+            }
+            (None, None) => {
+                self.operands.push(None);
+                Ok(())
+            }
+        }
+    }
+
+    fn local_type(&self, local: LocalIdx) -> Checked<ValType> {
+        let index = u32::from(local);
+        let run = self.locals.partition_point(|(end, _)| *end <= index);
+        self.locals
+            .get(run)
+            .map(|(_, value_type)| *value_type)
+            .ok_or(InvalidFuncBody::UnknownLocal(local))
+    }
+
+    fn is_defaultable_local(&self, local: LocalIdx, value_type: ValType) -> bool {
+        !matches!(
+            value_type,
+            ValType::Ref(RefType {
+                nullable: false,
+                ..
+            })
+        ) || u32::from(local) < self.parameter_count
+    }
+
+    fn set_local(&mut self, local: LocalIdx) -> Checked<ValType> {
+        let value_type = self.local_type(local)?;
+        self.pop_expected(value_type)?;
+
+        if !self.is_defaultable_local(local, value_type) && !self.inits.contains(&local) {
+            self.inits.push(local);
+        }
+
+        Ok(value_type)
+    }
+
+    fn global_type(&self, global: GlobalIdx) -> Checked<GlobalType> {
+        self.context
+            .global(global)
+            .ok_or(InvalidFuncBody::UnknownGlobal(global))
+    }
+
+    fn func_signature(&self, func: FuncIdx) -> Checked<TypeIdx> {
+        self.context
+            .func(func)
+            .ok_or(InvalidFuncBody::UnknownFunc(func))
+    }
+
+    fn call_type(&mut self, signature: TypeIdx) -> Checked {
+        let parameters = self.type_parameters(signature)?;
+        let results = self.type_results(signature)?;
+        self.operator(parameters, results)
+    }
+
+    fn return_call_type(&mut self, signature: TypeIdx) -> Checked {
+        let parameters = self.type_parameters(signature)?;
+        let results = self.type_results(signature)?;
+        let expected = self.return_types()?;
+
+        if results.len() != expected.len()
+            || results
+                .iter()
+                .zip(expected.iter())
+                .any(|(result, expected)| !self.matches(*result, expected))
+        {
+            return Err(InvalidFuncBody::TypeMismatch);
+        }
+
+        self.pop_types(Types::Slice(parameters))?;
+        self.set_unreachable();
+        Ok(())
+    }
+
+    fn table_type(&self, table: TableIdx) -> Checked<TableType> {
+        self.context
+            .table(table)
+            .ok_or(InvalidFuncBody::UnknownTable(table))
+    }
+
+    fn func_table_index_type(&self, table: TableIdx) -> Checked<ValType> {
+        let table_type = self.table_type(table)?;
+        if self.matches(
+            ValType::Ref(table_type.element_type),
+            ValType::Ref(RefType::FUNCREF),
+        ) {
+            Ok(table_type.index_type().into())
+        } else {
+            Err(InvalidFuncBody::TypeMismatch)
+        }
+    }
+
+    fn elem_type(&self, segment: ElemIdx) -> Checked<RefType> {
+        self.context
+            .elem(segment)
+            .ok_or(InvalidFuncBody::UnknownElem(segment))
+    }
+
+    fn memory_index_type(&self, memory: MemIdx) -> Checked<IdxType> {
+        self.context
+            .memory(memory)
+            .map(|memory_type| memory_type.index_type())
+            .ok_or(InvalidFuncBody::UnknownMemory(memory))
+    }
+
+    fn check_data(&self, segment: DataIdx) -> Checked {
+        match self.context.data_count() {
+            Some(count) if u32::from(segment) < count => Ok(()),
+            _ => Err(InvalidFuncBody::UnknownData(segment)),
+        }
+    }
+
+    /// Gets the type of the address operand of a memory access, checking that the alignment is
+    /// not larger than the `natural` alignment, expressed as an exponent of 2.
+    fn memory_address(&self, arg: MemArg, natural: u8) -> Checked<ValType> {
+        if arg.align.to_power() > natural {
+            return Err(InvalidFuncBody::Alignment(arg.align));
+        }
+
+        self.memory_index_type(arg.memory).map(ValType::from)
+    }
+
+    fn load(&mut self, arg: MemArg, natural: u8, result: ValType) -> Checked {
+        let address = self.memory_address(arg, natural)?;
+        self.pop_expected(address)?;
+        self.push(result);
+        Ok(())
+    }
+
+    fn store(&mut self, arg: MemArg, natural: u8, value: ValType) -> Checked {
+        let address = self.memory_address(arg, natural)?;
+        self.pop_expected(value)?;
+        self.pop_expected(address)
+    }
+
+    fn memory_op<E>(&mut self, opcode: Opcode, arg: MemArg) -> Result<(), E> {
+        use ValType::{F32, F64, I32, I64, V128};
+
+        let result = match opcode {
+            Opcode::I32Load => self.load(arg, 2, I32),
+            Opcode::I64Load => self.load(arg, 3, I64),
+            Opcode::F32Load => self.load(arg, 2, F32),
+            Opcode::F64Load => self.load(arg, 3, F64),
+            Opcode::I32Load8S | Opcode::I32Load8U => self.load(arg, 0, I32),
+            Opcode::I32Load16S | Opcode::I32Load16U => self.load(arg, 1, I32),
+            Opcode::I64Load8S | Opcode::I64Load8U => self.load(arg, 0, I64),
+            Opcode::I64Load16S | Opcode::I64Load16U => self.load(arg, 1, I64),
+            Opcode::I64Load32S | Opcode::I64Load32U => self.load(arg, 2, I64),
+            Opcode::I32Store => self.store(arg, 2, I32),
+            Opcode::I64Store => self.store(arg, 3, I64),
+            Opcode::F32Store => self.store(arg, 2, F32),
+            Opcode::F64Store => self.store(arg, 3, F64),
+            Opcode::I32Store8 => self.store(arg, 0, I32),
+            Opcode::I32Store16 => self.store(arg, 1, I32),
+            Opcode::I64Store8 => self.store(arg, 0, I64),
+            Opcode::I64Store16 => self.store(arg, 1, I64),
+            Opcode::I64Store32 => self.store(arg, 2, I64),
+            Opcode::V128Load => self.load(arg, 4, V128),
+            Opcode::V128Load8x8S
+            | Opcode::V128Load8x8U
+            | Opcode::V128Load16x4S
+            | Opcode::V128Load16x4U
+            | Opcode::V128Load32x2S
+            | Opcode::V128Load32x2U
+            | Opcode::V128Load64Splat
+            | Opcode::V128Load64Zero => self.load(arg, 3, V128),
+            Opcode::V128Load8Splat => self.load(arg, 0, V128),
+            Opcode::V128Load16Splat => self.load(arg, 1, V128),
+            Opcode::V128Load32Splat | Opcode::V128Load32Zero => self.load(arg, 2, V128),
+            Opcode::V128Store => self.store(arg, 4, V128),
+            _ => Err(InvalidFuncBody::Unsupported(opcode)),
+        };
+
+        report(result)
+    }
+
+    fn memory_lane_op<E>(&mut self, opcode: Opcode, arg: MemArg, lane: LaneIdx) -> Result<(), E> {
+        let (natural, lanes, is_load) = match opcode {
+            Opcode::V128Load8Lane => (0, 16, true),
+            Opcode::V128Load16Lane => (1, 8, true),
+            Opcode::V128Load32Lane => (2, 4, true),
+            Opcode::V128Load64Lane => (3, 2, true),
+            Opcode::V128Store8Lane => (0, 16, false),
+            Opcode::V128Store16Lane => (1, 8, false),
+            Opcode::V128Store32Lane => (2, 4, false),
+            Opcode::V128Store64Lane => (3, 2, false),
+            _ => return report(Err(InvalidFuncBody::Unsupported(opcode))),
+        };
+
+        self.check(|v| {
+            if lane >= lanes {
+                return Err(InvalidFuncBody::LaneIndex(lane));
+            }
+
+            let address = v.memory_address(arg, natural)?;
+            v.pop_expected(ValType::V128)?;
+            v.pop_expected(address)?;
+
+            if is_load {
+                v.push(ValType::V128);
+            }
+
+            Ok(())
+        })
+    }
+
+    fn lane_op<E>(&mut self, opcode: Opcode, lane: LaneIdx) -> Result<(), E> {
+        use ValType::{F32, F64, I32, I64};
+
+        let (lanes, lane_type, is_replace) = match opcode {
+            Opcode::I8x16ExtractLaneS | Opcode::I8x16ExtractLaneU => (16, I32, false),
+            Opcode::I8x16ReplaceLane => (16, I32, true),
+            Opcode::I16x8ExtractLaneS | Opcode::I16x8ExtractLaneU => (8, I32, false),
+            Opcode::I16x8ReplaceLane => (8, I32, true),
+            Opcode::I32x4ExtractLane => (4, I32, false),
+            Opcode::I32x4ReplaceLane => (4, I32, true),
+            Opcode::I64x2ExtractLane => (2, I64, false),
+            Opcode::I64x2ReplaceLane => (2, I64, true),
+            Opcode::F32x4ExtractLane => (4, F32, false),
+            Opcode::F32x4ReplaceLane => (4, F32, true),
+            Opcode::F64x2ExtractLane => (2, F64, false),
+            Opcode::F64x2ReplaceLane => (2, F64, true),
+            _ => return report(Err(InvalidFuncBody::Unsupported(opcode))),
+        };
+
+        self.check(|v| {
+            if lane >= lanes {
+                Err(InvalidFuncBody::LaneIndex(lane))
+            } else if is_replace {
+                v.operator(&[ValType::V128, lane_type], &[ValType::V128])
+            } else {
+                v.operator(&[ValType::V128], &[lane_type])
+            }
+        })
+    }
+
+    fn simple<E>(&mut self, opcode: Opcode) -> Result<(), E> {
+        macro_rules! op {
+            ([$($parameter:ident)*] -> [$($result:ident)*]) => {
+                self.operator(&[$(ValType::$parameter),*], &[$(ValType::$result),*])
+            };
+        }
+
+        let result = match opcode {
+            Opcode::Unreachable => {
+                self.set_unreachable();
+                Ok(())
+            }
+            Opcode::Nop => Ok(()),
+            Opcode::Else => self.else_block(),
+            Opcode::End => self.end_block(),
+            Opcode::Return => self.return_types().and_then(|results| {
+                self.pop_types(results)?;
+                self.set_unreachable();
+                Ok(())
+            }),
+            Opcode::Drop => self.pop().map(|_| ()),
+            Opcode::Select => self.select(),
+            Opcode::RefIsNull => match self.pop() {
+                Ok(None | Some(ValType::Ref(_))) => {
+                    self.push(ValType::I32);
+                    Ok(())
+                }
+                Ok(Some(_)) => Err(InvalidFuncBody::TypeMismatch),
+                Err(err) => Err(err),
+            },
+            Opcode::RefEq => {
+                let eqref =
+                    ValType::Ref(RefType::new(true, HeapType::unshared(AbstractHeapType::Eq)));
+                self.operator(&[eqref, eqref], &[ValType::I32])
+            }
+            Opcode::I32Eqz
+            | Opcode::I32Clz
+            | Opcode::I32Ctz
+            | Opcode::I32Popcnt
+            | Opcode::I32Extend8S
+            | Opcode::I32Extend16S => op!([I32] -> [I32]),
+            Opcode::I32Eq
+            | Opcode::I32Ne
+            | Opcode::I32LtS
+            | Opcode::I32LtU
+            | Opcode::I32GtS
+            | Opcode::I32GtU
+            | Opcode::I32LeS
+            | Opcode::I32LeU
+            | Opcode::I32GeS
+            | Opcode::I32GeU
+            | Opcode::I32Add
+            | Opcode::I32Sub
+            | Opcode::I32Mul
+            | Opcode::I32DivS
+            | Opcode::I32DivU
+            | Opcode::I32RemS
+            | Opcode::I32RemU
+            | Opcode::I32And
+            | Opcode::I32Or
+            | Opcode::I32Xor
+            | Opcode::I32Shl
+            | Opcode::I32ShrS
+            | Opcode::I32ShrU
+            | Opcode::I32Rotl
+            | Opcode::I32Rotr => op!([I32 I32] -> [I32]),
+            Opcode::I64Eqz | Opcode::I32WrapI64 => op!([I64] -> [I32]),
+            Opcode::I64Eq
+            | Opcode::I64Ne
+            | Opcode::I64LtS
+            | Opcode::I64LtU
+            | Opcode::I64GtS
+            | Opcode::I64GtU
+            | Opcode::I64LeS
+            | Opcode::I64LeU
+            | Opcode::I64GeS
+            | Opcode::I64GeU => op!([I64 I64] -> [I32]),
+            Opcode::I64Clz
+            | Opcode::I64Ctz
+            | Opcode::I64Popcnt
+            | Opcode::I64Extend8S
+            | Opcode::I64Extend16S
+            | Opcode::I64Extend32S => op!([I64] -> [I64]),
+            Opcode::I64Add
+            | Opcode::I64Sub
+            | Opcode::I64Mul
+            | Opcode::I64DivS
+            | Opcode::I64DivU
+            | Opcode::I64RemS
+            | Opcode::I64RemU
+            | Opcode::I64And
+            | Opcode::I64Or
+            | Opcode::I64Xor
+            | Opcode::I64Shl
+            | Opcode::I64ShrS
+            | Opcode::I64ShrU
+            | Opcode::I64Rotl
+            | Opcode::I64Rotr => op!([I64 I64] -> [I64]),
+            Opcode::F32Eq
+            | Opcode::F32Ne
+            | Opcode::F32Lt
+            | Opcode::F32Gt
+            | Opcode::F32Le
+            | Opcode::F32Ge => op!([F32 F32] -> [I32]),
+            Opcode::F64Eq
+            | Opcode::F64Ne
+            | Opcode::F64Lt
+            | Opcode::F64Gt
+            | Opcode::F64Le
+            | Opcode::F64Ge => op!([F64 F64] -> [I32]),
+            Opcode::F32Abs
+            | Opcode::F32Neg
+            | Opcode::F32Ceil
+            | Opcode::F32Floor
+            | Opcode::F32Trunc
+            | Opcode::F32Nearest
+            | Opcode::F32Sqrt => op!([F32] -> [F32]),
+            Opcode::F32Add
+            | Opcode::F32Sub
+            | Opcode::F32Mul
+            | Opcode::F32Div
+            | Opcode::F32Min
+            | Opcode::F32Max
+            | Opcode::F32Copysign => op!([F32 F32] -> [F32]),
+            Opcode::F64Abs
+            | Opcode::F64Neg
+            | Opcode::F64Ceil
+            | Opcode::F64Floor
+            | Opcode::F64Trunc
+            | Opcode::F64Nearest
+            | Opcode::F64Sqrt => op!([F64] -> [F64]),
+            Opcode::F64Add
+            | Opcode::F64Sub
+            | Opcode::F64Mul
+            | Opcode::F64Div
+            | Opcode::F64Min
+            | Opcode::F64Max
+            | Opcode::F64Copysign => op!([F64 F64] -> [F64]),
+            Opcode::I32TruncF32S
+            | Opcode::I32TruncF32U
+            | Opcode::I32ReinterpretF32
+            | Opcode::I32TruncSatF32S
+            | Opcode::I32TruncSatF32U => op!([F32] -> [I32]),
+            Opcode::I32TruncF64S
+            | Opcode::I32TruncF64U
+            | Opcode::I32TruncSatF64S
+            | Opcode::I32TruncSatF64U => op!([F64] -> [I32]),
+            Opcode::I64ExtendI32S | Opcode::I64ExtendI32U => op!([I32] -> [I64]),
+            Opcode::I64TruncF32S
+            | Opcode::I64TruncF32U
+            | Opcode::I64TruncSatF32S
+            | Opcode::I64TruncSatF32U => op!([F32] -> [I64]),
+            Opcode::I64TruncF64S
+            | Opcode::I64TruncF64U
+            | Opcode::I64ReinterpretF64
+            | Opcode::I64TruncSatF64S
+            | Opcode::I64TruncSatF64U => op!([F64] -> [I64]),
+            Opcode::F32ConvertI32S | Opcode::F32ConvertI32U | Opcode::F32ReinterpretI32 => {
+                op!([I32] -> [F32])
+            }
+            Opcode::F32ConvertI64S | Opcode::F32ConvertI64U => op!([I64] -> [F32]),
+            Opcode::F32DemoteF64 => op!([F64] -> [F32]),
+            Opcode::F64ConvertI32S | Opcode::F64ConvertI32U => op!([I32] -> [F64]),
+            Opcode::F64ConvertI64S | Opcode::F64ConvertI64U | Opcode::F64ReinterpretI64 => {
+                op!([I64] -> [F64])
+            }
+            Opcode::F64PromoteF32 => op!([F32] -> [F64]),
+            Opcode::I8x16Splat | Opcode::I16x8Splat | Opcode::I32x4Splat => {
+                op!([I32] -> [V128])
+            }
+            Opcode::I64x2Splat => op!([I64] -> [V128]),
+            Opcode::F32x4Splat => op!([F32] -> [V128]),
+            Opcode::F64x2Splat => op!([F64] -> [V128]),
+            Opcode::V128AnyTrue
+            | Opcode::I8x16AllTrue
+            | Opcode::I8x16Bitmask
+            | Opcode::I16x8AllTrue
+            | Opcode::I16x8Bitmask
+            | Opcode::I32x4AllTrue
+            | Opcode::I32x4Bitmask
+            | Opcode::I64x2AllTrue
+            | Opcode::I64x2Bitmask => op!([V128] -> [I32]),
+            Opcode::I8x16Shl
+            | Opcode::I8x16ShrS
+            | Opcode::I8x16ShrU
+            | Opcode::I16x8Shl
+            | Opcode::I16x8ShrS
+            | Opcode::I16x8ShrU
+            | Opcode::I32x4Shl
+            | Opcode::I32x4ShrS
+            | Opcode::I32x4ShrU
+            | Opcode::I64x2Shl
+            | Opcode::I64x2ShrS
+            | Opcode::I64x2ShrU => op!([V128 I32] -> [V128]),
+            Opcode::V128Not
+            | Opcode::F32x4DemoteF64x2Zero
+            | Opcode::F64x2PromoteLowF32x4
+            | Opcode::I8x16Abs
+            | Opcode::I8x16Neg
+            | Opcode::I8x16Popcnt
+            | Opcode::F32x4Ceil
+            | Opcode::F32x4Floor
+            | Opcode::F32x4Trunc
+            | Opcode::F32x4Nearest
+            | Opcode::F64x2Ceil
+            | Opcode::F64x2Floor
+            | Opcode::F64x2Trunc
+            | Opcode::I16x8ExtaddPairwiseI8x16S
+            | Opcode::I16x8ExtaddPairwiseI8x16U
+            | Opcode::I32x4ExtaddPairwiseI16x8S
+            | Opcode::I32x4ExtaddPairwiseI16x8U
+            | Opcode::I16x8Abs
+            | Opcode::I16x8Neg
+            | Opcode::I16x8ExtendLowI8x16S
+            | Opcode::I16x8ExtendHighI8x16S
+            | Opcode::I16x8ExtendLowI8x16U
+            | Opcode::I16x8ExtendHighI8x16U
+            | Opcode::F64x2Nearest
+            | Opcode::I32x4Abs
+            | Opcode::I32x4Neg
+            | Opcode::I32x4ExtendLowI16x8S
+            | Opcode::I32x4ExtendHighI16x8S
+            | Opcode::I32x4ExtendLowI16x8U
+            | Opcode::I32x4ExtendHighI16x8U
+            | Opcode::I64x2Abs
+            | Opcode::I64x2Neg
+            | Opcode::I64x2ExtendLowI32x4S
+            | Opcode::I64x2ExtendHighI32x4S
+            | Opcode::I64x2ExtendLowI32x4U
+            | Opcode::I64x2ExtendHighI32x4U
+            | Opcode::F32x4Abs
+            | Opcode::F32x4Neg
+            | Opcode::F32x4Sqrt
+            | Opcode::F64x2Abs
+            | Opcode::F64x2Neg
+            | Opcode::F64x2Sqrt
+            | Opcode::I32x4TruncSatF32x4S
+            | Opcode::I32x4TruncSatF32x4U
+            | Opcode::F32x4ConvertI32x4S
+            | Opcode::F32x4ConvertI32x4U
+            | Opcode::I32x4TruncSatF64x2SZero
+            | Opcode::I32x4TruncSatF64x2UZero
+            | Opcode::F64x2ConvertLowI32x4S
+            | Opcode::F64x2ConvertLowI32x4U
+            | Opcode::I32x4RelaxedTruncF32x4S
+            | Opcode::I32x4RelaxedTruncF32x4U
+            | Opcode::I32x4RelaxedTruncF64x2SZero
+            | Opcode::I32x4RelaxedTruncF64x2UZero => op!([V128] -> [V128]),
+            Opcode::I8x16Swizzle
+            | Opcode::I8x16Eq
+            | Opcode::I8x16Ne
+            | Opcode::I8x16LtS
+            | Opcode::I8x16LtU
+            | Opcode::I8x16GtS
+            | Opcode::I8x16GtU
+            | Opcode::I8x16LeS
+            | Opcode::I8x16LeU
+            | Opcode::I8x16GeS
+            | Opcode::I8x16GeU
+            | Opcode::I16x8Eq
+            | Opcode::I16x8Ne
+            | Opcode::I16x8LtS
+            | Opcode::I16x8LtU
+            | Opcode::I16x8GtS
+            | Opcode::I16x8GtU
+            | Opcode::I16x8LeS
+            | Opcode::I16x8LeU
+            | Opcode::I16x8GeS
+            | Opcode::I16x8GeU
+            | Opcode::I32x4Eq
+            | Opcode::I32x4Ne
+            | Opcode::I32x4LtS
+            | Opcode::I32x4LtU
+            | Opcode::I32x4GtS
+            | Opcode::I32x4GtU
+            | Opcode::I32x4LeS
+            | Opcode::I32x4LeU
+            | Opcode::I32x4GeS
+            | Opcode::I32x4GeU
+            | Opcode::F32x4Eq
+            | Opcode::F32x4Ne
+            | Opcode::F32x4Lt
+            | Opcode::F32x4Gt
+            | Opcode::F32x4Le
+            | Opcode::F32x4Ge
+            | Opcode::F64x2Eq
+            | Opcode::F64x2Ne
+            | Opcode::F64x2Lt
+            | Opcode::F64x2Gt
+            | Opcode::F64x2Le
+            | Opcode::F64x2Ge
+            | Opcode::V128And
+            | Opcode::V128AndNot
+            | Opcode::V128Or
+            | Opcode::V128Xor
+            | Opcode::I8x16NarrowI16x8S
+            | Opcode::I8x16NarrowI16x8U
+            | Opcode::I8x16Add
+            | Opcode::I8x16AddSatS
+            | Opcode::I8x16AddSatU
+            | Opcode::I8x16Sub
+            | Opcode::I8x16SubSatS
+            | Opcode::I8x16SubSatU
+            | Opcode::I8x16MinS
+            | Opcode::I8x16MinU
+            | Opcode::I8x16MaxS
+            | Opcode::I8x16MaxU
+            | Opcode::I8x16AvgrU
+            | Opcode::I16x8Q15mulrSatS
+            | Opcode::I16x8NarrowI32x4S
+            | Opcode::I16x8NarrowI32x4U
+            | Opcode::I16x8Add
+            | Opcode::I16x8AddSatS
+            | Opcode::I16x8AddSatU
+            | Opcode::I16x8Sub
+            | Opcode::I16x8SubSatS
+            | Opcode::I16x8SubSatU
+            | Opcode::I16x8Mul
+            | Opcode::I16x8MinS
+            | Opcode::I16x8MinU
+            | Opcode::I16x8MaxS
+            | Opcode::I16x8MaxU
+            | Opcode::I16x8AvgrU
+            | Opcode::I16x8ExtmulLowI8x16S
+            | Opcode::I16x8ExtmulHighI8x16S
+            | Opcode::I16x8ExtmulLowI8x16U
+            | Opcode::I16x8ExtmulHighI8x16U
+            | Opcode::I32x4Add
+            | Opcode::I32x4Sub
+            | Opcode::I32x4Mul
+            | Opcode::I32x4MinS
+            | Opcode::I32x4MinU
+            | Opcode::I32x4MaxS
+            | Opcode::I32x4MaxU
+            | Opcode::I32x4DotI16x8S
+            | Opcode::I32x4ExtmulLowI16x8S
+            | Opcode::I32x4ExtmulHighI16x8S
+            | Opcode::I32x4ExtmulLowI16x8U
+            | Opcode::I32x4ExtmulHighI16x8U
+            | Opcode::I64x2Add
+            | Opcode::I64x2Sub
+            | Opcode::I64x2Mul
+            | Opcode::I64x2Eq
+            | Opcode::I64x2Ne
+            | Opcode::I64x2LtS
+            | Opcode::I64x2GtS
+            | Opcode::I64x2LeS
+            | Opcode::I64x2GeS
+            | Opcode::I64x2ExtmulLowI32x4S
+            | Opcode::I64x2ExtmulHighI32x4S
+            | Opcode::I64x2ExtmulLowI32x4U
+            | Opcode::I64x2ExtmulHighI32x4U
+            | Opcode::F32x4Add
+            | Opcode::F32x4Sub
+            | Opcode::F32x4Mul
+            | Opcode::F32x4Div
+            | Opcode::F32x4Min
+            | Opcode::F32x4Max
+            | Opcode::F32x4Pmin
+            | Opcode::F32x4Pmax
+            | Opcode::F64x2Add
+            | Opcode::F64x2Sub
+            | Opcode::F64x2Mul
+            | Opcode::F64x2Div
+            | Opcode::F64x2Min
+            | Opcode::F64x2Max
+            | Opcode::F64x2Pmin
+            | Opcode::F64x2Pmax
+            | Opcode::I8x16RelaxedSwizzle
+            | Opcode::F32x4RelaxedMin
+            | Opcode::F32x4RelaxedMax
+            | Opcode::F64x2RelaxedMin
+            | Opcode::F64x2RelaxedMax
+            | Opcode::I16x8RelaxedQ15mulrS
+            | Opcode::I16x8RelaxedDotI8x16I7x16S => op!([V128 V128] -> [V128]),
+            Opcode::V128Bitselect
+            | Opcode::F32x4RelaxedMadd
+            | Opcode::F32x4RelaxedNmadd
+            | Opcode::F64x2RelaxedMadd
+            | Opcode::F64x2RelaxedNmadd
+            | Opcode::I8x16RelaxedLaneselect
+            | Opcode::I16x8RelaxedLaneselect
+            | Opcode::I32x4RelaxedLaneselect
+            | Opcode::I64x2RelaxedLaneselect
+            | Opcode::I32x4RelaxedDotI8x16I7x16AddS => op!([V128 V128 V128] -> [V128]),
+            _ => Err(InvalidFuncBody::Unsupported(opcode)),
+        };
+
+        report(result)
+    }
+}
+
+/// The proposals whose instructions are supported by the [`FuncValidator`].
+const SUPPORTED_PROPOSALS: WasmFeatures = WasmFeatures::MVP
+    .union(WasmFeatures::SIGN_EXT)
+    .union(WasmFeatures::NONTRAPPING_FPTOINT)
+    .union(WasmFeatures::BULK_MEMORY)
+    .union(WasmFeatures::REFERENCE_TYPES)
+    .union(WasmFeatures::SIMD128)
+    .union(WasmFeatures::RELAXED_SIMD)
+    .union(WasmFeatures::TAIL_CALL);
+
+fn is_supported(opcode: Opcode) -> bool {
+    opcode == Opcode::RefEq || SUPPORTED_PROPOSALS.contains(opcode.proposal())
+}
+
+fn report<E>(result: Checked) -> Result<(), E> {
+    result.map_err(|reason| ParseInstrError::Cause(ErrorCause::FuncValidation(reason)))
+}
+
+fn abstract_heap_type_matches(actual: AbstractHeapType, expected: AbstractHeapType) -> bool {
+    actual == expected
+        || matches!(
+            (actual, expected),
+            (AbstractHeapType::NoFunc, AbstractHeapType::Func)
+                | (AbstractHeapType::NoExtern, AbstractHeapType::Extern)
+                | (AbstractHeapType::NoExn, AbstractHeapType::Exn)
+                | (AbstractHeapType::NoCont, AbstractHeapType::Cont)
+                | (
+                    AbstractHeapType::None,
+                    AbstractHeapType::Any
+                        | AbstractHeapType::Eq
+                        | AbstractHeapType::I31
+                        | AbstractHeapType::Struct
+                        | AbstractHeapType::Array
+                )
+                | (
+                    AbstractHeapType::I31 | AbstractHeapType::Struct | AbstractHeapType::Array,
+                    AbstractHeapType::Any | AbstractHeapType::Eq
+                )
+                | (AbstractHeapType::Eq, AbstractHeapType::Any)
+        )
+}
+
+impl<C: ModuleContext + ?Sized, H: Heap> core::fmt::Debug for FuncValidator<'_, C, H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FuncValidator")
+            .field("offset", &self.offset)
+            .field("operands", &self.operands.len())
+            .field("frames", &self.frames.len())
+            .finish_non_exhaustive()
+    }
+}
+
+macro_rules! func_validator_method {
+    ($pascal_ident:ident $snake_ident:ident) => {
+        #[inline]
+        fn $snake_ident(&mut self) -> Result<(), E> {
+            self.simple(Opcode::$pascal_ident)
+        }
+    };
+    ($pascal_ident:ident $snake_ident:ident { arg: MemArg }) => {
+        #[inline]
+        fn $snake_ident(&mut self, arg: MemArg) -> Result<(), E> {
+            self.memory_op(Opcode::$pascal_ident, arg)
+        }
+    };
+    ($pascal_ident:ident $snake_ident:ident { arg: MemArg, lane: LaneIdx }) => {
+        #[inline]
+        fn $snake_ident(&mut self, arg: MemArg, lane: LaneIdx) -> Result<(), E> {
+            self.memory_lane_op(Opcode::$pascal_ident, arg, lane)
+        }
+    };
+    ($pascal_ident:ident $snake_ident:ident { lane: LaneIdx }) => {
+        #[inline]
+        fn $snake_ident(&mut self, lane: LaneIdx) -> Result<(), E> {
+            self.lane_op(Opcode::$pascal_ident, lane)
+        }
+    };
+    // Instructions with other immediate arguments are implemented separately
+    ($pascal_ident:ident $snake_ident:ident { $($_field_name:ident: $_field_type:ident),+ }) => {};
+}
+
+macro_rules! func_validator_definitions {
+    ($(
+        $_opcode_case:ident $_proposal:ident $_wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
+    )*) => {
+        $(
+            func_validator_method!($pascal_ident $snake_ident $({ $($field_name: $field_type),+ })?);
+        )*
+    };
+}
+
+impl<'a, C, H, E> ParseInstr<'a, E> for FuncValidator<'_, C, H>
+where
+    C: ModuleContext + ?Sized,
+    H: Heap,
+    E: ErrorSource<'a>,
+{
+    crate::isa::instr_definitions::all!(func_validator_definitions);
+
+    fn block(&mut self, block_type: BlockType) -> Result<(), E> {
+        self.check(|v| v.enter_block(FrameKind::Block, block_type))
+    }
+
+    fn r#loop(&mut self, block_type: BlockType) -> Result<(), E> {
+        self.check(|v| v.enter_block(FrameKind::Loop, block_type))
+    }
+
+    fn r#if(&mut self, block_type: BlockType) -> Result<(), E> {
+        self.check(|v| {
+            v.pop_expected(ValType::I32)?;
+            v.enter_block(FrameKind::If, block_type)
+        })
+    }
+
+    fn br(&mut self, target: LabelIdx) -> Result<(), E> {
+        self.check(|v| {
+            v.pop_types(v.label_types(target)?)?;
+            v.set_unreachable();
+            Ok(())
+        })
+    }
+
+    fn br_if(&mut self, target: LabelIdx) -> Result<(), E> {
+        self.check(|v| {
+            v.pop_expected(ValType::I32)?;
+            let types = v.label_types(target)?;
+            v.pop_types(types)?;
+            v.push_types(types);
+            Ok(())
+        })
+    }
+
+    fn br_table(&mut self, targets: &mut isa::BrTableTargets<'a, E>) -> Result<(), E> {
+        self.check(|v| v.pop_expected(ValType::I32))?;
+
+        let mut arity = None;
+        let mut default_types = Types::EMPTY;
+        for result in targets {
+            let target = result?;
+            self.check(|v| {
+                let types = v.label_types(target)?;
+                if *arity.get_or_insert(types.len()) != types.len() {
+                    return Err(InvalidFuncBody::TypeMismatch);
+                }
+
+                default_types = types;
+                v.peek_types(types)
+            })?;
+        }
+
+        // The last target is the default target
+        self.check(|v| {
+            v.pop_types(default_types)?;
+            v.set_unreachable();
+            Ok(())
+        })
+    }
+
+    fn call(&mut self, callee: FuncIdx) -> Result<(), E> {
+        self.check(|v| v.call_type(v.func_signature(callee)?))
+    }
+
+    fn call_indirect(&mut self, signature: TypeIdx, table: TableIdx) -> Result<(), E> {
+        self.check(|v| {
+            let index_type = v.func_table_index_type(table)?;
+            v.pop_expected(index_type)?;
+            v.call_type(signature)
+        })
+    }
+
+    fn return_call(&mut self, callee: FuncIdx) -> Result<(), E> {
+        self.check(|v| v.return_call_type(v.func_signature(callee)?))
+    }
+
+    fn return_call_indirect(&mut self, signature: TypeIdx, table: TableIdx) -> Result<(), E> {
+        self.check(|v| {
+            let index_type = v.func_table_index_type(table)?;
+            v.pop_expected(index_type)?;
+            v.return_call_type(signature)
+        })
+    }
+
+    fn select_typed(&mut self, types: &mut isa::SelectTypes<'a, E>) -> Result<(), E> {
+        let value_type = types.next().transpose()?;
+        let remaining = types.len();
+        self.check(|v| match value_type {
+            Some(value_type) if remaining == 0 => {
+                v.pop_expected(ValType::I32)?;
+                v.pop_expected(value_type)?;
+                v.pop_expected(value_type)?;
+                v.push(value_type);
+                Ok(())
+            }
+            // A typed `select` must have exactly one type
+            _ => Err(InvalidFuncBody::TypeMismatch),
+        })
+    }
+
+    fn local_get(&mut self, local: LocalIdx) -> Result<(), E> {
+        self.check(|v| {
+            let value_type = v.local_type(local)?;
+            if !v.is_defaultable_local(local, value_type) && !v.inits.contains(&local) {
+                return Err(InvalidFuncBody::UninitializedLocal(local));
+            }
+
+            v.push(value_type);
+            Ok(())
+        })
+    }
+
+    fn local_set(&mut self, local: LocalIdx) -> Result<(), E> {
+        self.check(|v| v.set_local(local).map(|_| ()))
+    }
+
+    fn local_tee(&mut self, local: LocalIdx) -> Result<(), E> {
+        self.check(|v| {
+            let value_type = v.set_local(local)?;
+            v.push(value_type);
+            Ok(())
+        })
+    }
+
+    fn global_get(&mut self, r#global: GlobalIdx) -> Result<(), E> {
+        self.check(|v| {
+            let global_type = v.global_type(r#global)?;
+            v.push(global_type.value_type);
+            Ok(())
+        })
+    }
+
+    fn global_set(&mut self, r#global: GlobalIdx) -> Result<(), E> {
+        self.check(|v| {
+            let global_type = v.global_type(r#global)?;
+            if global_type.mutability != Mutability::Variable {
+                return Err(InvalidFuncBody::ImmutableGlobal(r#global));
+            }
+
+            v.pop_expected(global_type.value_type)
+        })
+    }
+
+    fn memory_size(&mut self, memory: MemIdx) -> Result<(), E> {
+        self.check(|v| {
+            let address = v.memory_index_type(memory)?.into();
+            v.push(address);
+            Ok(())
+        })
+    }
+
+    fn memory_grow(&mut self, memory: MemIdx) -> Result<(), E> {
+        self.check(|v| {
+            let address = v.memory_index_type(memory)?.into();
+            v.operator(&[address], &[address])
+        })
+    }
+
+    fn memory_fill(&mut self, memory: MemIdx) -> Result<(), E> {
+        self.check(|v| {
+            let address = v.memory_index_type(memory)?.into();
+            v.operator(&[address, ValType::I32, address], &[])
+        })
+    }
+
+    fn memory_copy(&mut self, destination: MemIdx, source: MemIdx) -> Result<(), E> {
+        self.check(|v| {
+            let destination = v.memory_index_type(destination)?;
+            let source = v.memory_index_type(source)?;
+            let length = destination.min(source);
+            v.operator(&[destination.into(), source.into(), length.into()], &[])
+        })
+    }
+
+    fn memory_init(&mut self, segment: DataIdx, memory: MemIdx) -> Result<(), E> {
+        self.check(|v| {
+            v.check_data(segment)?;
+            let address = v.memory_index_type(memory)?.into();
+            v.operator(&[address, ValType::I32, ValType::I32], &[])
+        })
+    }
+
+    fn data_drop(&mut self, segment: DataIdx) -> Result<(), E> {
+        self.check(|v| v.check_data(segment))
+    }
+
+    fn table_get(&mut self, table: TableIdx) -> Result<(), E> {
+        self.check(|v| {
+            let table_type = v.table_type(table)?;
+            v.operator(
+                &[table_type.index_type().into()],
+                &[ValType::Ref(table_type.element_type)],
+            )
+        })
+    }
+
+    fn table_set(&mut self, table: TableIdx) -> Result<(), E> {
+        self.check(|v| {
+            let table_type = v.table_type(table)?;
+            v.operator(
+                &[
+                    table_type.index_type().into(),
+                    ValType::Ref(table_type.element_type),
+                ],
+                &[],
+            )
+        })
+    }
+
+    fn table_size(&mut self, table: TableIdx) -> Result<(), E> {
+        self.check(|v| {
+            let table_type = v.table_type(table)?;
+            v.push(table_type.index_type().into());
+            Ok(())
+        })
+    }
+
+    fn table_grow(&mut self, table: TableIdx) -> Result<(), E> {
+        self.check(|v| {
+            let table_type = v.table_type(table)?;
+            let index_type = table_type.index_type().into();
+            v.operator(
+                &[ValType::Ref(table_type.element_type), index_type],
+                &[index_type],
+            )
+        })
+    }
+
+    fn table_fill(&mut self, table: TableIdx) -> Result<(), E> {
+        self.check(|v| {
+            let table_type = v.table_type(table)?;
+            let index_type = table_type.index_type().into();
+            v.operator(
+                &[
+                    index_type,
+                    ValType::Ref(table_type.element_type),
+                    index_type,
+                ],
+                &[],
+            )
+        })
+    }
+
+    fn table_copy(&mut self, destination: TableIdx, source: TableIdx) -> Result<(), E> {
+        self.check(|v| {
+            let destination = v.table_type(destination)?;
+            let source = v.table_type(source)?;
+            if !v.matches(
+                ValType::Ref(source.element_type),
+                ValType::Ref(destination.element_type),
+            ) {
+                return Err(InvalidFuncBody::TypeMismatch);
+            }
+
+            let length = destination.index_type().min(source.index_type());
+            v.operator(
+                &[
+                    destination.index_type().into(),
+                    source.index_type().into(),
+                    length.into(),
+                ],
+                &[],
+            )
+        })
+    }
+
+    fn table_init(&mut self, segment: ElemIdx, table: TableIdx) -> Result<(), E> {
+        self.check(|v| {
+            let element_type = v.elem_type(segment)?;
+            let table_type = v.table_type(table)?;
+            if !v.matches(
+                ValType::Ref(element_type),
+                ValType::Ref(table_type.element_type),
+            ) {
+                return Err(InvalidFuncBody::TypeMismatch);
+            }
+
+            v.operator(
+                &[table_type.index_type().into(), ValType::I32, ValType::I32],
+                &[],
+            )
+        })
+    }
+
+    fn elem_drop(&mut self, segment: ElemIdx) -> Result<(), E> {
+        self.check(|v| v.elem_type(segment).map(|_| ()))
+    }
+
+    fn ref_null(&mut self, heap_type: HeapType) -> Result<(), E> {
+        self.check(|v| {
+            if let HeapType::Index(index) = heap_type {
+                if !v.context.is_type_defined(index) {
+                    return Err(InvalidFuncBody::UnknownType(index));
+                }
+            }
+
+            v.push(ValType::Ref(RefType::new(true, heap_type)));
+            Ok(())
+        })
+    }
+
+    fn ref_func(&mut self, target: FuncIdx) -> Result<(), E> {
+        self.check(|v| {
+            let signature = v.func_signature(target)?;
+            if !v.context.is_func_declared(target) {
+                return Err(InvalidFuncBody::UndeclaredFuncRef(target));
+            }
+
+            v.push(ValType::Ref(RefType::new(
+                false,
+                HeapType::Index(signature),
+            )));
+            Ok(())
+        })
+    }
+
+    fn i32_const(&mut self, _: i32) -> Result<(), E> {
+        self.push(ValType::I32);
+        Ok(())
+    }
+
+    fn i64_const(&mut self, _: i64) -> Result<(), E> {
+        self.push(ValType::I64);
+        Ok(())
+    }
+
+    fn f32_const(&mut self, _: crate::values::F32) -> Result<(), E> {
+        self.push(ValType::F32);
+        Ok(())
+    }
+
+    fn f64_const(&mut self, _: crate::values::F64) -> Result<(), E> {
+        self.push(ValType::F64);
+        Ok(())
+    }
+
+    fn v128_const(&mut self, _: crate::values::V128) -> Result<(), E> {
+        self.push(ValType::V128);
+        Ok(())
+    }
+
+    fn i8x16_shuffle(&mut self, lanes: V128ShuffleLanes) -> Result<(), E> {
+        self.check(|v| {
+            if let Some(lane) = lanes.0.iter().find(|lane| **lane >= 32) {
+                return Err(InvalidFuncBody::LaneIndex(*lane));
+            }
+
+            v.operator(&[ValType::V128, ValType::V128], &[ValType::V128])
+        })
+    }
+}
+
+/// Validates the local declarations and [**`expr`**](isa::expr()) of a function body, whose
+/// function type is at index `signature`.
+///
+/// The `body` refers to the contents of the function body, which the validator's
+/// [offset](FuncValidator::offset) is relative to.
+pub(crate) fn validate_func_body<'a, C, H, E>(
+    body: &'a [u8],
+    signature: TypeIdx,
+    validator: &mut FuncValidator<'_, C, H>,
+) -> crate::Parsed<'a, (), E>
+where
+    C: ModuleContext + ?Sized,
+    H: Heap,
+    E: ErrorSource<'a>,
+{
+    validator.begin(signature).map_err(|reason| {
+        nom::Err::Failure(E::from_error_kind_and_cause(
+            body,
+            ErrorKind::Verify,
+            ErrorCause::FuncValidation(reason),
+        ))
+    })?;
+
+    let mut locals = Locals::<E>::with_parsed_length(body, LocalsParser::default())?;
+    for result in &mut locals {
+        let (count, value_type) = result?;
+        if validator.define_locals(count, value_type).is_none() {
+            return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                body,
+                ErrorKind::TooLarge,
+                ErrorCause::LocalsOverflow,
+            )));
+        }
+    }
+
+    let (instructions, _) = locals.finish()?;
+    isa::expr::expr_inspect(instructions, validator, |input, validator| {
+        validator.offset = (body.len() - input.len()).try_into().unwrap_or(u32::MAX);
+
+        // Instructions with immediate arguments would otherwise not be recognized
+        let (_, opcode) = Opcode::parse(input)?;
+        if is_supported(opcode) {
+            Ok(())
+        } else {
+            Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                ErrorKind::Verify,
+                ErrorCause::FuncValidation(InvalidFuncBody::Unsupported(opcode)),
+            )))
+        }
+    })
+    .map(|(input, _)| (input, ()))
+}
@@ -10,8 +10,10 @@
 use crate::{
     error::ErrorSource,
     isa,
-    module::{DataIdx, ElemIdx, FuncIdx, GlobalIdx, LocalIdx, MemIdx, TableIdx, TagIdx, TypeIdx},
-    types::{BlockType, RefType, ValType},
+    module::{
+        DataIdx, ElemIdx, FieldIdx, FuncIdx, GlobalIdx, LocalIdx, MemIdx, TableIdx, TagIdx, TypeIdx,
+    },
+    types::{BlockType, HeapType, IdxType, RefType, TableType, ValType},
     values::{V128ShuffleLanes, F32, F64, V128},
 };
 use allocator_api2::{
@@ -25,7 +27,7 @@ use core::{
     marker::PhantomData,
 };
 
-pub use isa::{LabelIdx, LaneIdx, MemArg, Opcode};
+pub use isa::{AtomicOrdering, LabelIdx, LaneIdx, MemArg, Opcode};
 
 macro_rules! instr_case_common {
     ($opcode_enum:ident $wasm_name:literal $pascal_ident:ident) => {
@@ -114,7 +116,9 @@ const fn mem_arg_natural_align(name: &'static str) -> isa::Align {
         | b"i32.atomic.rmw16.xchg_u"
         | b"i64.atomic.rmw16.xchg_u"
         | b"i32.atomic.rmw16.cmpxchg_u"
-        | b"i64.atomic.rmw16.cmpxchg_u" => isa::Align::Two,
+        | b"i64.atomic.rmw16.cmpxchg_u"
+        | b"f32.load_f16"
+        | b"f32.store_f16" => isa::Align::Two,
         b"i32.load"
         | b"f32.load"
         | b"i32.store"
@@ -215,6 +219,26 @@ macro_rules! instr_case_common_display {
             }
         }
     };
+    (RefTest { heap_type: HeapType }) => {
+        instr_case_common_display!(RefTest { heap_type: HeapType } => false);
+    };
+    (RefTestNull { heap_type: HeapType }) => {
+        instr_case_common_display!(RefTestNull { heap_type: HeapType } => true);
+    };
+    (RefCast { heap_type: HeapType }) => {
+        instr_case_common_display!(RefCast { heap_type: HeapType } => false);
+    };
+    (RefCastNull { heap_type: HeapType }) => {
+        instr_case_common_display!(RefCastNull { heap_type: HeapType } => true);
+    };
+    ($pascal_ident:ident { heap_type: HeapType } => $nullable:literal) => {
+        impl<A: Allocator> Display for $pascal_ident<A> {
+            fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+                f.write_str(Self::NAME)?;
+                write!(f, " {}", RefType::new($nullable, self.heap_type))
+            }
+        }
+    };
     ($pascal_ident:ident { arg: MemArg }) => {
         impl<A: Allocator> $pascal_ident<A> {
             const NATURAL_ALIGN: isa::Align = mem_arg_natural_align(Self::NAME);
@@ -229,6 +253,7 @@ macro_rules! instr_case_common_display {
     };
     ($pascal_ident:ident { n: $integer_type:ident }) => {
         impl<A: Allocator> Display for $pascal_ident<A> {
+            #[allow(clippy::cast_possible_truncation)]
             fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
                 f.write_str(Self::NAME)?;
                 let width = 2 + (<$integer_type>::BITS as usize / 4);
@@ -328,6 +353,15 @@ macro_rules! instr_case {
     (ByteOpcode $wasm_name:literal SelectTyped { types: SelectTypes }) => {
         instr_case_common!(ByteOpcode $wasm_name SelectTyped);
     };
+    (ByteOpcode $wasm_name:literal TryTable { block_type: BlockType, catches: CatchClauses }) => {
+        instr_case_common!(ByteOpcode $wasm_name TryTable);
+    };
+    (ByteOpcode $wasm_name:literal Resume { cont_type: TypeIdx, handlers: ResumeHandlers }) => {
+        instr_case_common!(ByteOpcode $wasm_name Resume);
+    };
+    (ByteOpcode $wasm_name:literal ResumeThrow { cont_type: TypeIdx, tag: TagIdx, handlers: ResumeHandlers }) => {
+        instr_case_common!(ByteOpcode $wasm_name ResumeThrow);
+    };
     {
         $opcode_enum:ident $wasm_name:literal $pascal_ident:ident $({
             $($field_name:ident: $field_type:ident),+
@@ -459,6 +493,155 @@ impl<A: Allocator> Display for SelectTyped<A> {
     }
 }
 
+#[derive(Clone)]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub struct TryTable<A: Allocator = Global> {
+    pub block_type: BlockType,
+    pub catches: Box<[isa::CatchClause], A>,
+}
+
+impl<A1: Allocator, A2: Allocator> PartialEq<TryTable<A2>> for TryTable<A1> {
+    #[inline]
+    fn eq(&self, other: &TryTable<A2>) -> bool {
+        let self_catches: &[isa::CatchClause] = &self.catches;
+        let other_catches: &[isa::CatchClause] = &other.catches;
+        self.block_type == other.block_type && self_catches == other_catches
+    }
+}
+
+impl<A: Allocator> Eq for TryTable<A> {}
+
+impl<A: Allocator> Hash for TryTable<A> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.block_type.hash(state);
+        <&[isa::CatchClause]>::hash(&&*self.catches, state);
+    }
+}
+
+impl<A: Allocator> Debug for TryTable<A> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.debug_struct("TryTable")
+            .field("block_type", &self.block_type)
+            .field("catches", &&*self.catches)
+            .finish()
+    }
+}
+
+impl<A: Allocator> Display for TryTable<A> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.write_str(Self::NAME)?;
+        match self.block_type {
+            BlockType::Empty => (),
+            BlockType::Index(idx) => write!(f, " (type {idx})")?,
+            BlockType::Inline(ty) => write!(f, " (result {ty})")?,
+        }
+
+        for catch in self.catches.iter() {
+            write!(f, " {catch}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub struct Resume<A: Allocator = Global> {
+    pub cont_type: TypeIdx,
+    pub handlers: Box<[isa::ResumeHandler], A>,
+}
+
+impl<A1: Allocator, A2: Allocator> PartialEq<Resume<A2>> for Resume<A1> {
+    #[inline]
+    fn eq(&self, other: &Resume<A2>) -> bool {
+        let self_handlers: &[isa::ResumeHandler] = &self.handlers;
+        let other_handlers: &[isa::ResumeHandler] = &other.handlers;
+        self.cont_type == other.cont_type && self_handlers == other_handlers
+    }
+}
+
+impl<A: Allocator> Eq for Resume<A> {}
+
+impl<A: Allocator> Hash for Resume<A> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.cont_type.hash(state);
+        <&[isa::ResumeHandler]>::hash(&&*self.handlers, state);
+    }
+}
+
+impl<A: Allocator> Debug for Resume<A> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.debug_struct("Resume")
+            .field("cont_type", &self.cont_type)
+            .field("handlers", &&*self.handlers)
+            .finish()
+    }
+}
+
+impl<A: Allocator> Display for Resume<A> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{} {}", Self::NAME, self.cont_type)?;
+        for handler in self.handlers.iter() {
+            write!(f, " {handler}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub struct ResumeThrow<A: Allocator = Global> {
+    pub cont_type: TypeIdx,
+    pub tag: TagIdx,
+    pub handlers: Box<[isa::ResumeHandler], A>,
+}
+
+impl<A1: Allocator, A2: Allocator> PartialEq<ResumeThrow<A2>> for ResumeThrow<A1> {
+    #[inline]
+    fn eq(&self, other: &ResumeThrow<A2>) -> bool {
+        let self_handlers: &[isa::ResumeHandler] = &self.handlers;
+        let other_handlers: &[isa::ResumeHandler] = &other.handlers;
+        self.cont_type == other.cont_type
+            && self.tag == other.tag
+            && self_handlers == other_handlers
+    }
+}
+
+impl<A: Allocator> Eq for ResumeThrow<A> {}
+
+impl<A: Allocator> Hash for ResumeThrow<A> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.cont_type.hash(state);
+        self.tag.hash(state);
+        <&[isa::ResumeHandler]>::hash(&&*self.handlers, state);
+    }
+}
+
+impl<A: Allocator> Debug for ResumeThrow<A> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        f.debug_struct("ResumeThrow")
+            .field("cont_type", &self.cont_type)
+            .field("tag", &self.tag)
+            .field("handlers", &&*self.handlers)
+            .finish()
+    }
+}
+
+impl<A: Allocator> Display for ResumeThrow<A> {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "{} {} {}", Self::NAME, self.cont_type, self.tag)?;
+        for handler in self.handlers.iter() {
+            write!(f, " {handler}")?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Error type used in [`ParseExpr`] to indicate that an [`Instr`]uction is not recognized.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct UnrecognizedInstr;
@@ -563,6 +746,70 @@ where
             .map_err(|UnrecognizedInstr| isa::ParseInstrError::Unrecognized)
     }
 
+    fn try_table_impl(
+        &mut self,
+        block_type: BlockType,
+        catches: &mut isa::CatchClauses<'a, E>,
+    ) -> isa::Result<(), E> {
+        let mut clauses = Vec::with_capacity_in(catches.len(), self.allocator.clone());
+        for result in catches {
+            clauses.push(result?);
+        }
+
+        let instr = Instr::TryTable(TryTable {
+            block_type,
+            catches: clauses.into_boxed_slice(),
+        });
+
+        self.parser
+            .parse(instr)
+            .map_err(|UnrecognizedInstr| isa::ParseInstrError::Unrecognized)
+    }
+
+    fn resume_handlers(
+        &self,
+        handlers: &mut isa::ResumeHandlers<'a, E>,
+    ) -> isa::Result<Box<[isa::ResumeHandler], A>, E> {
+        let mut clauses = Vec::with_capacity_in(handlers.len(), self.allocator.clone());
+        for result in handlers {
+            clauses.push(result?);
+        }
+
+        Ok(clauses.into_boxed_slice())
+    }
+
+    fn resume_impl(
+        &mut self,
+        cont_type: TypeIdx,
+        handlers: &mut isa::ResumeHandlers<'a, E>,
+    ) -> isa::Result<(), E> {
+        let instr = Instr::Resume(Resume {
+            cont_type,
+            handlers: self.resume_handlers(handlers)?,
+        });
+
+        self.parser
+            .parse(instr)
+            .map_err(|UnrecognizedInstr| isa::ParseInstrError::Unrecognized)
+    }
+
+    fn resume_throw_impl(
+        &mut self,
+        cont_type: TypeIdx,
+        tag: TagIdx,
+        handlers: &mut isa::ResumeHandlers<'a, E>,
+    ) -> isa::Result<(), E> {
+        let instr = Instr::ResumeThrow(ResumeThrow {
+            cont_type,
+            tag,
+            handlers: self.resume_handlers(handlers)?,
+        });
+
+        self.parser
+            .parse(instr)
+            .map_err(|UnrecognizedInstr| isa::ParseInstrError::Unrecognized)
+    }
+
     fn select_typed_impl(&mut self, types: &mut isa::SelectTypes<'a, E>) -> isa::Result<(), E> {
         let start = crate::input::AsInput::as_input(types);
         let result = types
@@ -624,6 +871,24 @@ macro_rules! parse_method_impl {
             self.select_typed_impl(types)
         }
     };
+    (try_table<$_lifetime:lifetime, $error:ident>(block_type: BlockType, catches: CatchClauses) => TryTable) => {
+        #[inline]
+        fn try_table(&mut self, block_type: BlockType, catches: &mut isa::CatchClauses<'a, E>) -> isa::Result<(), $error> {
+            self.try_table_impl(block_type, catches)
+        }
+    };
+    (resume<$_lifetime:lifetime, $error:ident>(cont_type: TypeIdx, handlers: ResumeHandlers) => Resume) => {
+        #[inline]
+        fn resume(&mut self, cont_type: TypeIdx, handlers: &mut isa::ResumeHandlers<'a, E>) -> isa::Result<(), $error> {
+            self.resume_impl(cont_type, handlers)
+        }
+    };
+    (resume_throw<$_lifetime:lifetime, $error:ident>(cont_type: TypeIdx, tag: TagIdx, handlers: ResumeHandlers) => ResumeThrow) => {
+        #[inline]
+        fn resume_throw(&mut self, cont_type: TypeIdx, tag: TagIdx, handlers: &mut isa::ResumeHandlers<'a, E>) -> isa::Result<(), $error> {
+            self.resume_throw_impl(cont_type, tag, handlers)
+        }
+    };
     ($snake_ident:ident<$_lifetime:lifetime, $error:ident>($($($field_name:ident: $field_type:ident),+)?) => $pascal_ident:ident) => {
         fn $snake_ident(&mut self $(, $($field_name: $field_type),+)?) -> isa::Result<(), $error> {
             let instr = Instr::$pascal_ident($pascal_ident {
@@ -640,7 +905,7 @@ macro_rules! parse_method_impl {
 
 macro_rules! instr_enum {
     ($(
-        $opcode_case:ident $wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
+        $opcode_case:ident $_proposal:ident $wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
     )*) => {
         /// Represents a WebAssembly [instruction].
         ///
@@ -725,3 +990,44 @@ macro_rules! instr_enum {
 }
 
 crate::isa::instr_definitions::all!(instr_enum);
+
+impl<A: Allocator> Instr<A> {
+    /// Gets the [`IdxType`] of the table operands of a table instruction, such as
+    /// **`table.get`** or **`call_indirect`**, given a closure that returns the type of each
+    /// table in the module.
+    ///
+    /// For instructions that use two tables, such as **`table.copy`**, this returns
+    /// [`IdxType::I64`] only if both tables have 64-bit indices.
+    ///
+    /// Returns `None` if the instruction does not use a table, or if `table_type` returns
+    /// `None`.
+    pub fn table_index_type<F>(&self, mut table_type: F) -> Option<IdxType>
+    where
+        F: FnMut(TableIdx) -> Option<TableType>,
+    {
+        let mut index_type = |table| table_type(table).map(|table_type| table_type.index_type());
+
+        match self {
+            Self::CallIndirect(CallIndirect { table, .. })
+            | Self::ReturnCallIndirect(ReturnCallIndirect { table, .. })
+            | Self::TableGet(TableGet { table, .. })
+            | Self::TableSet(TableSet { table, .. })
+            | Self::TableSize(TableSize { table, .. })
+            | Self::TableGrow(TableGrow { table, .. })
+            | Self::TableFill(TableFill { table, .. })
+            | Self::TableInit(TableInit { table, .. })
+            | Self::TableAtomicGet(TableAtomicGet { table, .. })
+            | Self::TableAtomicSet(TableAtomicSet { table, .. })
+            | Self::TableAtomicRmwXchg(TableAtomicRmwXchg { table, .. })
+            | Self::TableAtomicRmwCmpxchg(TableAtomicRmwCmpxchg { table, .. }) => {
+                index_type(*table)
+            }
+            Self::TableCopy(TableCopy {
+                destination,
+                source,
+                ..
+            }) => Some(index_type(*destination)?.min(index_type(*source)?)),
+            _ => None,
+        }
+    }
+}
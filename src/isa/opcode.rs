@@ -1,11 +1,12 @@
 use crate::{
     error::{self, AddCause as _, ErrorCause},
+    features::WasmFeatures,
     isa::{self, InvalidOpcode},
 };
 
 macro_rules! opcode_enum {
     ($(
-        $opcode_case:ident $wasm_name:literal $pascal_ident:ident $({ $($_fields:tt)* })? $_snake_ident:ident;
+        $opcode_case:ident $proposal:ident $wasm_name:literal $pascal_ident:ident $({ $($_fields:tt)* })? $_snake_ident:ident;
     )*) => {
         /// Represents the full opcode of an instruction.
         ///
@@ -22,9 +23,12 @@ macro_rules! opcode_enum {
             /// Gets a list of all of the opcodes supported by [`nom-wasm`](crate).
             pub const ALL: &[Self] = &[$(Self::$pascal_ident,)*];
             const WASM_NAMES: &[*const u8] = &[$($wasm_name.as_ptr(),)*];
+            #[allow(clippy::cast_possible_truncation)]
             const WASM_NAME_LENS: &[u8] = &[$($wasm_name.len() as u8,)*];
             const DEBUG_NAMES: &[*const u8] = &[$(stringify!($pascal_ident).as_ptr(),)*];
+            #[allow(clippy::cast_possible_truncation)]
             const DEBUG_NAME_LENS: &[u8] = &[$(stringify!($pascal_ident).len() as u8,)*];
+            const PROPOSALS: &[WasmFeatures] = &[$(crate::features::from_proposal!($proposal),)*];
         }
     };
 }
@@ -48,6 +52,7 @@ opcode_partial_eq! {
     isa::FCPrefixedOpcode,
     isa::V128Opcode,
     isa::FEPrefixedOpcode,
+    isa::FBPrefixedOpcode,
 }
 
 fn parse_failed<'a, E>(input: &'a [u8], error: InvalidOpcode) -> nom::Err<E>
@@ -103,7 +108,8 @@ impl Opcode {
         parse_actual! {
             isa::FCPrefixedOpcode,
             isa::V128Opcode,
-            isa::FEPrefixedOpcode
+            isa::FEPrefixedOpcode,
+            isa::FBPrefixedOpcode
         }
     }
 
@@ -124,6 +130,15 @@ impl Opcode {
             core::str::from_utf8_unchecked(bytes)
         }
     }
+
+    /// Gets the proposal that introduced the instruction that this opcode corresponds to, such
+    /// as [`WasmFeatures::SIMD128`].
+    ///
+    /// Instructions from the original WebAssembly specification return [`WasmFeatures::MVP`].
+    #[inline]
+    pub const fn proposal(self) -> WasmFeatures {
+        Self::PROPOSALS[self as usize]
+    }
 }
 
 impl core::fmt::Debug for Opcode {
@@ -122,6 +122,7 @@ byte_opcodes! {
     Catch = 0x07,
     Throw = 0x08,
     Rethrow = 0x09,
+    ThrowRef = 0x0A,
     End = 0x0B,
     Br = 0x0C,
     BrIf = 0x0D,
@@ -131,6 +132,8 @@ byte_opcodes! {
     CallIndirect = 0x11,
     ReturnCall = 0x12,
     ReturnCallIndirect = 0x13,
+    CallRef = 0x14,
+    ReturnCallRef = 0x15,
     Delegate = 0x18,
     CatchAll = 0x19,
 
@@ -139,6 +142,7 @@ byte_opcodes! {
     /// Alternative opcode for [`select`](Opcode::Select), used to explicitly specify the types of
     /// operands.
     SelectTyped = 0x1C,
+    TryTable = 0x1F,
 
     LocalGet = 0x20,
     LocalSet = 0x21,
@@ -325,6 +329,17 @@ byte_opcodes! {
     RefNull = 0xD0,
     RefIsNull = 0xD1,
     RefFunc = 0xD2,
+    RefEq = 0xD3,
+    RefAsNonNull = 0xD4,
+    BrOnNull = 0xD5,
+    BrOnNonNull = 0xD6,
+
+    ContNew = 0xE0,
+    ContBind = 0xE1,
+    Suspend = 0xE2,
+    Resume = 0xE3,
+    ResumeThrow = 0xE4,
+    Switch = 0xE5,
 }
 
 prefixed_opcodes! {
@@ -334,10 +349,14 @@ prefixed_opcodes! {
     /// - The [non-trapping float-to-integer conversions] proposal.
     /// - The [bulk-memory operations] proposal.
     /// - The [reference types] proposal.
+    /// - The [wide arithmetic] proposal.
+    /// - The [half precision] proposal, which introduced loads and stores of 16-bit floats.
     ///
     /// [non-trapping float-to-integer conversions]: https://github.com/WebAssembly/nontrapping-float-to-int-conversions
     /// [bulk-memory operations]: https://github.com/WebAssembly/bulk-memory-operations
     /// [reference types]: https://github.com/WebAssembly/reference-types
+    /// [wide arithmetic]: https://github.com/WebAssembly/wide-arithmetic
+    /// [half precision]: https://github.com/WebAssembly/half-precision
     FCPrefixedOpcode(0xFC) {
         I32TruncSatF32S = 0,
         I32TruncSatF32U = 1,
@@ -359,14 +378,25 @@ prefixed_opcodes! {
         TableGrow = 15,
         TableSize = 16,
         TableFill = 17,
+
+        I64Add128 = 0x13,
+        I64Sub128 = 0x14,
+        I64MulWideS = 0x15,
+        I64MulWideU = 0x16,
+
+        F32LoadF16 = 0x30,
+        F32StoreF16 = 0x31,
     }
 
     /// An opcode value for an instruction prefixed by the `0xFE` ``Opcode`].
     ///
     /// The feature proposals that introduced these opcodes include:
     /// - The [threads] proposal, which introduced atomic memory instructions.
+    /// - The [shared-everything threads] proposal, which introduced atomic instructions for
+    ///   globals, tables, structs, and arrays.
     ///
     /// [threads]: https://github.com/webassembly/threads
+    /// [shared-everything threads]: https://github.com/WebAssembly/shared-everything-threads
     FEPrefixedOpcode(0xFE) {
         MemoryAtomicNotify = 0,
         MemoryAtomicWait32 = 1,
@@ -444,6 +474,45 @@ prefixed_opcodes! {
         I64AtomicRmw8CmpxchgU = 0x4C,
         I64AtomicRmw16CmpxchgU = 0x4D,
         I64AtomicRmw32CmpxchgU = 0x4E,
+
+        GlobalAtomicGet = 0x4F,
+        GlobalAtomicSet = 0x50,
+        GlobalAtomicRmwAdd = 0x51,
+        GlobalAtomicRmwSub = 0x52,
+        GlobalAtomicRmwAnd = 0x53,
+        GlobalAtomicRmwOr = 0x54,
+        GlobalAtomicRmwXor = 0x55,
+        GlobalAtomicRmwXchg = 0x56,
+        GlobalAtomicRmwCmpxchg = 0x57,
+
+        TableAtomicGet = 0x58,
+        TableAtomicSet = 0x59,
+        TableAtomicRmwXchg = 0x5A,
+        TableAtomicRmwCmpxchg = 0x5B,
+
+        StructAtomicGet = 0x5C,
+        StructAtomicGetS = 0x5D,
+        StructAtomicGetU = 0x5E,
+        StructAtomicSet = 0x5F,
+        StructAtomicRmwAdd = 0x60,
+        StructAtomicRmwSub = 0x61,
+        StructAtomicRmwAnd = 0x62,
+        StructAtomicRmwOr = 0x63,
+        StructAtomicRmwXor = 0x64,
+        StructAtomicRmwXchg = 0x65,
+        StructAtomicRmwCmpxchg = 0x66,
+
+        ArrayAtomicGet = 0x67,
+        ArrayAtomicGetS = 0x68,
+        ArrayAtomicGetU = 0x69,
+        ArrayAtomicSet = 0x6A,
+        ArrayAtomicRmwAdd = 0x6B,
+        ArrayAtomicRmwSub = 0x6C,
+        ArrayAtomicRmwAnd = 0x6D,
+        ArrayAtomicRmwOr = 0x6E,
+        ArrayAtomicRmwXor = 0x6F,
+        ArrayAtomicRmwXchg = 0x70,
+        ArrayAtomicRmwCmpxchg = 0x71,
     }
 
     /// An opcode value for a [128-bit vector instruction], which is an instruction prefixed by the
@@ -453,10 +522,12 @@ prefixed_opcodes! {
     /// - The [fixed-width SIMD proposal], which introduced the [`0xFD` opcode] prefix.
     /// - The [relaxed SIMD proposal], which introduced additional opcodes on top of the
     ///   [fixed-width SIMD proposal].
+    /// - The [half precision proposal], which introduced `f16x8` vector instructions.
     ///
     /// [128-bit vector instruction]: https://webassembly.github.io/spec/core/binary/instructions.html#vector-instructions
     /// [fixed-width SIMD proposal]: https://github.com/webassembly/simd
     /// [relaxed SIMD proposal]: https://github.com/WebAssembly/relaxed-simd
+    /// [half precision proposal]: https://github.com/WebAssembly/half-precision
     V128Opcode(0xFD) {
         V128Load = 0,
         V128Load8x8S = 1,
@@ -746,7 +817,91 @@ prefixed_opcodes! {
         I16x8RelaxedDotI8x16I7x16S = 0x112,
         I32x4RelaxedDotI8x16I7x16AddS = 0x113,
 
-        // Relaxed SIMD Reserved Range (0x114 - 0x12F)
+        // Relaxed SIMD Reserved Range (0x114 - 0x11F)
+
+        F16x8Splat = 0x120,
+        F16x8ExtractLane = 0x121,
+        F16x8ReplaceLane = 0x122,
+
+        F16x8Abs = 0x130,
+        F16x8Neg = 0x131,
+        F16x8Sqrt = 0x132,
+        F16x8Ceil = 0x133,
+        F16x8Floor = 0x134,
+        F16x8Trunc = 0x135,
+        F16x8Nearest = 0x136,
+        F16x8Eq = 0x137,
+        F16x8Ne = 0x138,
+        F16x8Lt = 0x139,
+        F16x8Gt = 0x13A,
+        F16x8Le = 0x13B,
+        F16x8Ge = 0x13C,
+        F16x8Add = 0x13D,
+        F16x8Sub = 0x13E,
+        F16x8Mul = 0x13F,
+        F16x8Div = 0x140,
+        F16x8Min = 0x141,
+        F16x8Max = 0x142,
+        F16x8Pmin = 0x143,
+        F16x8Pmax = 0x144,
+        I16x8TruncSatF16x8S = 0x145,
+        I16x8TruncSatF16x8U = 0x146,
+        F16x8ConvertI16x8S = 0x147,
+        F16x8ConvertI16x8U = 0x148,
+        F16x8DemoteF32x4Zero = 0x149,
+        F16x8DemoteF64x2Zero = 0x14A,
+        F32x4PromoteLowF16x8 = 0x14B,
+
+        F16x8RelaxedMadd = 0x14E,
+        F16x8RelaxedNmadd = 0x14F,
+    }
+
+    /// An opcode value for an instruction prefixed by the `0xFB` [`Opcode`].
+    ///
+    /// The feature proposals that introduced these opcodes include:
+    /// - The [garbage collection proposal], which introduced instructions for aggregate and
+    ///   unboxed scalar types.
+    /// - The [shared-everything threads proposal], which introduced **`ref.i31_shared`**.
+    ///
+    /// [garbage collection proposal]: https://github.com/WebAssembly/gc
+    /// [shared-everything threads proposal]: https://github.com/WebAssembly/shared-everything-threads
+    FBPrefixedOpcode(0xFB) {
+        StructNew = 0,
+        StructNewDefault = 1,
+        StructGet = 2,
+        StructGetS = 3,
+        StructGetU = 4,
+        StructSet = 5,
+
+        ArrayNew = 6,
+        ArrayNewDefault = 7,
+        ArrayNewFixed = 8,
+        ArrayNewData = 9,
+        ArrayNewElem = 10,
+        ArrayGet = 11,
+        ArrayGetS = 12,
+        ArrayGetU = 13,
+        ArraySet = 14,
+        ArrayLen = 15,
+        ArrayFill = 16,
+        ArrayCopy = 17,
+        ArrayInitData = 18,
+        ArrayInitElem = 19,
+
+        RefTest = 20,
+        RefTestNull = 21,
+        RefCast = 22,
+        RefCastNull = 23,
+        BrOnCast = 24,
+        BrOnCastFail = 25,
+
+        AnyConvertExtern = 26,
+        ExternConvertAny = 27,
+
+        RefI31 = 28,
+        I31GetS = 29,
+        I31GetU = 30,
+        RefI31Shared = 31,
     }
 }
 
@@ -754,6 +909,7 @@ static_assert::check_size!(ByteOpcode, <= 1);
 static_assert::check_size!(FCPrefixedOpcode, <= 1);
 static_assert::check_size!(FEPrefixedOpcode, <= 1);
 static_assert::check_size!(V128Opcode, <= 2);
+static_assert::check_size!(FBPrefixedOpcode, <= 1);
 
 impl TryFrom<u8> for ByteOpcode {
     type Error = InvalidOpcode;
@@ -0,0 +1,133 @@
+use crate::{
+    error::ErrorSource,
+    isa::{self, AtomicOrdering, LabelIdx, LaneIdx, MemArg, ParseInstr, Result},
+    module::{
+        custom::{BranchLikelihood, FuncBranchHints},
+        DataIdx, ElemIdx, FieldIdx, FuncIdx, GlobalIdx, LocalIdx, MemIdx, TableIdx, TagIdx,
+        TypeIdx,
+    },
+    types::{BlockType, HeapType, RefType},
+    values::{V128ShuffleLanes, F32, F64, V128},
+};
+use nom::ToUsize as _;
+
+/// Trait for parsing [WebAssembly instructions] along with the [`BranchHint`]s that apply to
+/// them.
+///
+/// See the documentation for [`FuncBody::parse_expr_with_branch_hints()`] for more information.
+///
+/// [WebAssembly instructions]: https://webassembly.github.io/spec/core/binary/instructions.html
+/// [`BranchHint`]: crate::module::custom::BranchHint
+/// [`FuncBody::parse_expr_with_branch_hints()`]: crate::module::FuncBody::parse_expr_with_branch_hints
+pub trait ParseBranchHint<'a, E: ErrorSource<'a>>: ParseInstr<'a, E> {
+    /// Called immediately before the [**`br_if`**] or [**`if`**] instruction that a hint applies
+    /// to.
+    ///
+    /// [**`br_if`**]: ParseInstr::br_if
+    /// [**`if`**]: ParseInstr::if
+    fn branch_hint(&mut self, likelihood: BranchLikelihood) -> Result<(), E>;
+}
+
+impl<'a, E, P> ParseBranchHint<'a, E> for &mut P
+where
+    E: ErrorSource<'a>,
+    P: ParseBranchHint<'a, E>,
+{
+    #[inline]
+    fn branch_hint(&mut self, likelihood: BranchLikelihood) -> Result<(), E> {
+        <P>::branch_hint(self, likelihood)
+    }
+}
+
+struct BranchHintInstr<P> {
+    pending: Option<BranchLikelihood>,
+    parser: P,
+}
+
+macro_rules! branch_hint_method {
+    (@hinted $name:ident($($parameter:ident: $parameter_ty:ty),*)) => {
+        #[inline]
+        fn $name(&mut self $(, $parameter: $parameter_ty)*) -> Result<(), E> {
+            if let Some(likelihood) = self.pending.take() {
+                self.parser.branch_hint(likelihood)?;
+            }
+
+            self.parser.$name($($parameter),*)
+        }
+    };
+    (br_if($($parameter:ident: $parameter_ty:ty),*)) => {
+        branch_hint_method!(@hinted br_if($($parameter: $parameter_ty),*));
+    };
+    (r#if($($parameter:ident: $parameter_ty:ty),*)) => {
+        branch_hint_method!(@hinted r#if($($parameter: $parameter_ty),*));
+    };
+    ($name:ident($($parameter:ident: $parameter_ty:ty),*)) => {
+        #[inline]
+        fn $name(&mut self $(, $parameter: $parameter_ty)*) -> Result<(), E> {
+            self.parser.$name($($parameter),*)
+        }
+    };
+}
+
+macro_rules! branch_hint_definitions {
+    ($(
+        $_opcode_case:ident $_proposal:ident $_wasm_name:literal $_pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
+    )*) => {
+        $(
+            isa::parse_instr::instr_method_declaration!(branch_hint_method($snake_ident $({ $($field_name: $field_type),+ })?));
+        )*
+    };
+}
+
+impl<'a, P, E> ParseInstr<'a, E> for BranchHintInstr<P>
+where
+    P: ParseBranchHint<'a, E>,
+    E: ErrorSource<'a>,
+{
+    crate::isa::instr_definitions::all!(branch_hint_definitions);
+}
+
+/// Parses the [**`expr`**](isa::expr()) of a function body, passing each of the `hints` to the
+/// `parser` before the instruction at the hint's offset.
+///
+/// The `body` refers to the contents of the function body, which the offsets of the `hints` are
+/// relative to. Hints that do not refer to the start of a **`br_if`** or **`if`** instruction are
+/// ignored.
+pub(crate) fn expr_with_branch_hints<'a, P, E>(
+    body: &'a [u8],
+    instructions: &'a [u8],
+    hints: &FuncBranchHints<'a>,
+    parser: P,
+) -> crate::Parsed<'a, P, E>
+where
+    P: ParseBranchHint<'a, E>,
+    E: ErrorSource<'a>,
+{
+    let mut hints = hints.iter::<E>();
+    let mut next_hint = hints.next().transpose()?;
+    let state = BranchHintInstr {
+        pending: None,
+        parser,
+    };
+
+    isa::expr::expr_inspect(instructions, state, |input, state| {
+        let offset = body.len() - input.len();
+        state.pending = None;
+
+        while let Some(hint) = next_hint {
+            let hint_offset = hint.offset.to_usize();
+            if hint_offset > offset {
+                break;
+            }
+
+            if hint_offset == offset {
+                state.pending = Some(hint.likelihood);
+            }
+
+            next_hint = hints.next().transpose()?;
+        }
+
+        Ok(())
+    })
+    .map(|(input, state)| (input, state.parser))
+}
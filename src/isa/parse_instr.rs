@@ -1,8 +1,10 @@
 use crate::{
     error::ErrorSource,
-    isa::{self, LabelIdx, LaneIdx, MemArg},
-    module::{DataIdx, ElemIdx, FuncIdx, GlobalIdx, LocalIdx, MemIdx, TableIdx, TagIdx, TypeIdx},
-    types::{BlockType, RefType},
+    isa::{self, AtomicOrdering, LabelIdx, LaneIdx, MemArg},
+    module::{
+        DataIdx, ElemIdx, FieldIdx, FuncIdx, GlobalIdx, LocalIdx, MemIdx, TableIdx, TagIdx, TypeIdx,
+    },
+    types::{BlockType, HeapType, RefType},
     values::{V128ShuffleLanes, F32, F64, V128},
 };
 
@@ -55,6 +57,15 @@ macro_rules! instr_method_declaration {
     ($macro_name:ident(select_typed { types: SelectTypes })) => {
         $macro_name!(select_typed(types: &mut isa::SelectTypes<'a, E>));
     };
+    ($macro_name:ident(try_table { block_type: BlockType, catches: CatchClauses })) => {
+        $macro_name!(try_table(block_type: BlockType, catches: &mut isa::CatchClauses<'a, E>));
+    };
+    ($macro_name:ident(resume { cont_type: TypeIdx, handlers: ResumeHandlers })) => {
+        $macro_name!(resume(cont_type: TypeIdx, handlers: &mut isa::ResumeHandlers<'a, E>));
+    };
+    ($macro_name:ident(resume_throw { cont_type: TypeIdx, tag: TagIdx, handlers: ResumeHandlers })) => {
+        $macro_name!(resume_throw(cont_type: TypeIdx, tag: TagIdx, handlers: &mut isa::ResumeHandlers<'a, E>));
+    };
     ($macro_name:ident($name:ident $({ $($field_name:ident: $field_type:ident),+ })?)) => {
         $macro_name!($name($($($field_name: $field_type),+)?));
     };
@@ -64,7 +75,7 @@ pub(in crate::isa) use instr_method_declaration;
 
 macro_rules! parse_instr_method {
     ($(
-        $_opcode_enum:ident $_wasm_name:literal $_pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
+        $_opcode_enum:ident $_proposal:ident $_wasm_name:literal $_pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
     )*) => {
         $(
             instr_method_declaration!(instr_method_define_default($snake_ident $({ $($field_name: $field_type),+ })?));
@@ -95,7 +106,7 @@ macro_rules! instr_method_define_delegate {
 
 macro_rules! parse_instr_method_noop {
     ($(
-        $opcode_case:ident $wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
+        $opcode_case:ident $_proposal:ident $wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
     )*) => {
         $(
             instr_method_declaration!(instr_method_define_delegate($snake_ident $({ $($field_name: $field_type),+ })?));
@@ -118,7 +129,7 @@ macro_rules! instr_method_define_delegate {
 
 macro_rules! parse_instr_delegate_method {
     ($(
-        $opcode_case:ident $wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
+        $opcode_case:ident $_proposal:ident $wasm_name:literal $pascal_ident:ident $({ $($field_name:ident: $field_type:ident),+ })? $snake_ident:ident;
     )*) => {
         $(
             instr_method_declaration!(instr_method_define_delegate($snake_ident $({ $($field_name: $field_type),+ })?));
@@ -1,8 +1,12 @@
 use crate::{
-    error::{AddCause as _, ErrorCause, ErrorKind, ErrorSource, InvalidInstr},
+    error::{
+        AddCause as _, ErrorCause, ErrorKind, ErrorSource, InvalidFlags, InvalidFlagsValue,
+        InvalidInstr,
+    },
     index::Index as _,
     isa::{self, Opcode, ParseInstr},
     module::{self, MemIdx, TableIdx, TypeIdx},
+    types::{HeapType, RefType},
 };
 
 trait ResultExt<'a, T, E: ErrorSource<'a>> {
@@ -124,6 +128,46 @@ where
         }};
     }
 
+    macro_rules! br_on_cast {
+        ($case:ident) => {{
+            let flags_start = input;
+            let (input, flags) = if let Some((flags, input)) = input.split_first() {
+                (input, *flags)
+            } else {
+                return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                    input,
+                    ErrorKind::Eof,
+                    ErrorCause::InvalidFlags(InvalidFlags::CastFlags(InvalidFlagsValue::Missing)),
+                )));
+            };
+
+            const FROM_NULLABLE: u8 = 1;
+            const TO_NULLABLE: u8 = 0b10;
+
+            let invalid = flags & !(FROM_NULLABLE | TO_NULLABLE);
+            if invalid != 0 {
+                return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                    &flags_start[..1],
+                    ErrorKind::Verify,
+                    ErrorCause::InvalidFlags(InvalidFlags::CastFlags(InvalidFlagsValue::Invalid {
+                        value: flags,
+                        invalid,
+                    })),
+                )));
+            }
+
+            let (input, target) = isa::LabelIdx::parse(input).add_cause_with(bad_argument)?;
+            let (input, from_heap_type) = HeapType::parse(input).add_cause_with(bad_argument)?;
+            let (input, to_heap_type) = HeapType::parse(input).add_cause_with(bad_argument)?;
+            let from_type = RefType::new(flags & FROM_NULLABLE != 0, from_heap_type);
+            let to_type = RefType::new(flags & TO_NULLABLE != 0, to_heap_type);
+            parser
+                .$case(target, from_type, to_type)
+                .to_parsed(start, opcode)?;
+            input
+        }};
+    }
+
     let input = match opcode {
         Opcode::Unreachable => empty_case!(unreachable),
         Opcode::Nop => empty_case!(nop),
@@ -149,8 +193,11 @@ where
         Opcode::Drop => empty_case!(drop),
         Opcode::Select => empty_case!(select),
         Opcode::SelectTyped => {
-            let mut types = isa::SelectTypes::with_parsed_length(input, Default::default())
-                .add_cause_with(bad_argument)?;
+            let mut types = isa::SelectTypes::with_parsed_length(
+                input,
+                crate::types::ValTypeParser::with_features(crate::features::WasmFeatures::ALL),
+            )
+            .add_cause_with(bad_argument)?;
 
             parser.select_typed(&mut types).to_parsed(start, opcode)?;
             types.finish().add_cause_with(bad_argument)?.0
@@ -325,7 +372,7 @@ where
         Opcode::I64Extend8S => empty_case!(i64_extend8_s),
         Opcode::I64Extend16S => empty_case!(i64_extend16_s),
         Opcode::I64Extend32S => empty_case!(i64_extend32_s),
-        Opcode::RefNull => single_argument!(crate::types::RefType => ref_null),
+        Opcode::RefNull => single_argument!(HeapType => ref_null),
         Opcode::RefIsNull => empty_case!(ref_is_null),
         Opcode::RefFunc => single_argument!(module::FuncIdx => ref_func),
         Opcode::TableGet => single_argument!(TableIdx => table_get),
@@ -334,12 +381,56 @@ where
         Opcode::ReturnCallIndirect => {
             simple_arguments!(signature: TypeIdx, table: TableIdx => return_call_indirect)
         }
+        Opcode::CallRef => single_argument!(TypeIdx => call_ref),
+        Opcode::ReturnCallRef => single_argument!(TypeIdx => return_call_ref),
+        Opcode::BrOnNull => single_argument!(isa::LabelIdx => br_on_null),
+        Opcode::BrOnNonNull => single_argument!(isa::LabelIdx => br_on_non_null),
+        Opcode::RefAsNonNull => empty_case!(ref_as_non_null),
         Opcode::Try => block_start!(r#try),
         Opcode::Catch => single_argument!(module::TagIdx => r#catch),
         Opcode::Throw => single_argument!(module::TagIdx => r#throw),
         Opcode::Rethrow => single_argument!(isa::LabelIdx => rethrow),
         Opcode::Delegate => single_argument!(isa::LabelIdx => delegate),
         Opcode::CatchAll => empty_case!(catch_all),
+        Opcode::TryTable => {
+            let (input, block_type) =
+                crate::types::BlockType::parse(input).add_cause_with(bad_argument)?;
+
+            let mut catches = isa::CatchClauses::with_input(input).add_cause_with(bad_argument)?;
+
+            parser
+                .try_table(block_type, &mut catches)
+                .to_parsed(start, opcode)?;
+            catches.finish().add_cause_with(bad_argument)?.0
+        }
+        Opcode::ThrowRef => empty_case!(throw_ref),
+        Opcode::ContNew => single_argument!(TypeIdx => cont_new),
+        Opcode::ContBind => simple_arguments!(source: TypeIdx, target: TypeIdx => cont_bind),
+        Opcode::Suspend => single_argument!(module::TagIdx => suspend),
+        Opcode::Resume => {
+            let (input, cont_type) = TypeIdx::parse(input).add_cause_with(bad_argument)?;
+            let mut handlers =
+                isa::ResumeHandlers::with_input(input).add_cause_with(bad_argument)?;
+
+            parser
+                .resume(cont_type, &mut handlers)
+                .to_parsed(start, opcode)?;
+            handlers.finish().add_cause_with(bad_argument)?.0
+        }
+        Opcode::ResumeThrow => {
+            let (input, cont_type) = TypeIdx::parse(input).add_cause_with(bad_argument)?;
+            let (input, tag) = module::TagIdx::parse(input).add_cause_with(bad_argument)?;
+            let mut handlers =
+                isa::ResumeHandlers::with_input(input).add_cause_with(bad_argument)?;
+
+            parser
+                .resume_throw(cont_type, tag, &mut handlers)
+                .to_parsed(start, opcode)?;
+            handlers.finish().add_cause_with(bad_argument)?.0
+        }
+        Opcode::Switch => {
+            simple_arguments!(cont_type: TypeIdx, tag: module::TagIdx => switch)
+        }
         Opcode::I32TruncSatF32S => empty_case!(i32_trunc_sat_f32_s),
         Opcode::I32TruncSatF32U => empty_case!(i32_trunc_sat_f32_u),
         Opcode::I32TruncSatF64S => empty_case!(i32_trunc_sat_f64_s),
@@ -626,6 +717,45 @@ where
         Opcode::I32x4RelaxedDotI8x16I7x16AddS => {
             empty_case!(i32x4_relaxed_dot_i8x16_i7x16_add_s)
         }
+        Opcode::I64Add128 => empty_case!(i64_add128),
+        Opcode::I64Sub128 => empty_case!(i64_sub128),
+        Opcode::I64MulWideS => empty_case!(i64_mul_wide_s),
+        Opcode::I64MulWideU => empty_case!(i64_mul_wide_u),
+        Opcode::F32LoadF16 => mem_op!(f32_load_f16),
+        Opcode::F32StoreF16 => mem_op!(f32_store_f16),
+        Opcode::F16x8Splat => empty_case!(f16x8_splat),
+        Opcode::F16x8ExtractLane => v128_lane_op!(f16x8_extract_lane),
+        Opcode::F16x8ReplaceLane => v128_lane_op!(f16x8_replace_lane),
+        Opcode::F16x8Abs => empty_case!(f16x8_abs),
+        Opcode::F16x8Neg => empty_case!(f16x8_neg),
+        Opcode::F16x8Sqrt => empty_case!(f16x8_sqrt),
+        Opcode::F16x8Ceil => empty_case!(f16x8_ceil),
+        Opcode::F16x8Floor => empty_case!(f16x8_floor),
+        Opcode::F16x8Trunc => empty_case!(f16x8_trunc),
+        Opcode::F16x8Nearest => empty_case!(f16x8_nearest),
+        Opcode::F16x8Eq => empty_case!(f16x8_eq),
+        Opcode::F16x8Ne => empty_case!(f16x8_ne),
+        Opcode::F16x8Lt => empty_case!(f16x8_lt),
+        Opcode::F16x8Gt => empty_case!(f16x8_gt),
+        Opcode::F16x8Le => empty_case!(f16x8_le),
+        Opcode::F16x8Ge => empty_case!(f16x8_ge),
+        Opcode::F16x8Add => empty_case!(f16x8_add),
+        Opcode::F16x8Sub => empty_case!(f16x8_sub),
+        Opcode::F16x8Mul => empty_case!(f16x8_mul),
+        Opcode::F16x8Div => empty_case!(f16x8_div),
+        Opcode::F16x8Min => empty_case!(f16x8_min),
+        Opcode::F16x8Max => empty_case!(f16x8_max),
+        Opcode::F16x8Pmin => empty_case!(f16x8_pmin),
+        Opcode::F16x8Pmax => empty_case!(f16x8_pmax),
+        Opcode::I16x8TruncSatF16x8S => empty_case!(i16x8_trunc_sat_f16x8_s),
+        Opcode::I16x8TruncSatF16x8U => empty_case!(i16x8_trunc_sat_f16x8_u),
+        Opcode::F16x8ConvertI16x8S => empty_case!(f16x8_convert_i16x8_s),
+        Opcode::F16x8ConvertI16x8U => empty_case!(f16x8_convert_i16x8_u),
+        Opcode::F16x8DemoteF32x4Zero => empty_case!(f16x8_demote_f32x4_zero),
+        Opcode::F16x8DemoteF64x2Zero => empty_case!(f16x8_demote_f64x2_zero),
+        Opcode::F32x4PromoteLowF16x8 => empty_case!(f32x4_promote_low_f16x8),
+        Opcode::F16x8RelaxedMadd => empty_case!(f16x8_relaxed_madd),
+        Opcode::F16x8RelaxedNmadd => empty_case!(f16x8_relaxed_nmadd),
         Opcode::MemoryAtomicNotify => mem_op!(memory_atomic_notify),
         Opcode::MemoryAtomicWait32 => mem_op!(memory_atomic_wait32),
         Opcode::MemoryAtomicWait64 => mem_op!(memory_atomic_wait64),
@@ -692,6 +822,167 @@ where
         Opcode::I64AtomicRmw8CmpxchgU => mem_op!(i64_atomic_rmw8_cmpxchg_u),
         Opcode::I64AtomicRmw16CmpxchgU => mem_op!(i64_atomic_rmw16_cmpxchg_u),
         Opcode::I64AtomicRmw32CmpxchgU => mem_op!(i64_atomic_rmw32_cmpxchg_u),
+        Opcode::StructNew => single_argument!(TypeIdx => struct_new),
+        Opcode::StructNewDefault => single_argument!(TypeIdx => struct_new_default),
+        Opcode::StructGet => {
+            simple_arguments!(struct_type: TypeIdx, field: module::FieldIdx => struct_get)
+        }
+        Opcode::StructGetS => {
+            simple_arguments!(struct_type: TypeIdx, field: module::FieldIdx => struct_get_s)
+        }
+        Opcode::StructGetU => {
+            simple_arguments!(struct_type: TypeIdx, field: module::FieldIdx => struct_get_u)
+        }
+        Opcode::StructSet => {
+            simple_arguments!(struct_type: TypeIdx, field: module::FieldIdx => struct_set)
+        }
+        Opcode::ArrayNew => single_argument!(TypeIdx => array_new),
+        Opcode::ArrayNewDefault => single_argument!(TypeIdx => array_new_default),
+        Opcode::ArrayNewFixed => {
+            let (input, array_type) = TypeIdx::parse(input).add_cause_with(bad_argument)?;
+            let (input, length) = crate::values::leb128_u32(input).add_cause_with(bad_argument)?;
+            parser
+                .array_new_fixed(array_type, length)
+                .to_parsed(start, opcode)?;
+            input
+        }
+        Opcode::ArrayNewData => {
+            simple_arguments!(array_type: TypeIdx, segment: module::DataIdx => array_new_data)
+        }
+        Opcode::ArrayNewElem => {
+            simple_arguments!(array_type: TypeIdx, segment: module::ElemIdx => array_new_elem)
+        }
+        Opcode::ArrayGet => single_argument!(TypeIdx => array_get),
+        Opcode::ArrayGetS => single_argument!(TypeIdx => array_get_s),
+        Opcode::ArrayGetU => single_argument!(TypeIdx => array_get_u),
+        Opcode::ArraySet => single_argument!(TypeIdx => array_set),
+        Opcode::ArrayLen => empty_case!(array_len),
+        Opcode::ArrayFill => single_argument!(TypeIdx => array_fill),
+        Opcode::ArrayCopy => copy_op!(TypeIdx => array_copy),
+        Opcode::ArrayInitData => {
+            simple_arguments!(array_type: TypeIdx, segment: module::DataIdx => array_init_data)
+        }
+        Opcode::ArrayInitElem => {
+            simple_arguments!(array_type: TypeIdx, segment: module::ElemIdx => array_init_elem)
+        }
+        Opcode::RefEq => empty_case!(ref_eq),
+        Opcode::RefTest => single_argument!(HeapType => ref_test),
+        Opcode::RefTestNull => single_argument!(HeapType => ref_test_null),
+        Opcode::RefCast => single_argument!(HeapType => ref_cast),
+        Opcode::RefCastNull => single_argument!(HeapType => ref_cast_null),
+        Opcode::BrOnCast => br_on_cast!(br_on_cast),
+        Opcode::BrOnCastFail => br_on_cast!(br_on_cast_fail),
+        Opcode::AnyConvertExtern => empty_case!(any_convert_extern),
+        Opcode::ExternConvertAny => empty_case!(extern_convert_any),
+        Opcode::RefI31 => empty_case!(ref_i31),
+        Opcode::I31GetS => empty_case!(i31_get_s),
+        Opcode::I31GetU => empty_case!(i31_get_u),
+        Opcode::RefI31Shared => empty_case!(ref_i31_shared),
+        Opcode::GlobalAtomicGet => {
+            simple_arguments!(ordering: isa::AtomicOrdering, r#global: module::GlobalIdx => global_atomic_get)
+        }
+        Opcode::GlobalAtomicSet => {
+            simple_arguments!(ordering: isa::AtomicOrdering, r#global: module::GlobalIdx => global_atomic_set)
+        }
+        Opcode::GlobalAtomicRmwAdd => {
+            simple_arguments!(ordering: isa::AtomicOrdering, r#global: module::GlobalIdx => global_atomic_rmw_add)
+        }
+        Opcode::GlobalAtomicRmwSub => {
+            simple_arguments!(ordering: isa::AtomicOrdering, r#global: module::GlobalIdx => global_atomic_rmw_sub)
+        }
+        Opcode::GlobalAtomicRmwAnd => {
+            simple_arguments!(ordering: isa::AtomicOrdering, r#global: module::GlobalIdx => global_atomic_rmw_and)
+        }
+        Opcode::GlobalAtomicRmwOr => {
+            simple_arguments!(ordering: isa::AtomicOrdering, r#global: module::GlobalIdx => global_atomic_rmw_or)
+        }
+        Opcode::GlobalAtomicRmwXor => {
+            simple_arguments!(ordering: isa::AtomicOrdering, r#global: module::GlobalIdx => global_atomic_rmw_xor)
+        }
+        Opcode::GlobalAtomicRmwXchg => {
+            simple_arguments!(ordering: isa::AtomicOrdering, r#global: module::GlobalIdx => global_atomic_rmw_xchg)
+        }
+        Opcode::GlobalAtomicRmwCmpxchg => {
+            simple_arguments!(ordering: isa::AtomicOrdering, r#global: module::GlobalIdx => global_atomic_rmw_cmpxchg)
+        }
+        Opcode::TableAtomicGet => {
+            simple_arguments!(ordering: isa::AtomicOrdering, table: TableIdx => table_atomic_get)
+        }
+        Opcode::TableAtomicSet => {
+            simple_arguments!(ordering: isa::AtomicOrdering, table: TableIdx => table_atomic_set)
+        }
+        Opcode::TableAtomicRmwXchg => {
+            simple_arguments!(ordering: isa::AtomicOrdering, table: TableIdx => table_atomic_rmw_xchg)
+        }
+        Opcode::TableAtomicRmwCmpxchg => {
+            simple_arguments!(ordering: isa::AtomicOrdering, table: TableIdx => table_atomic_rmw_cmpxchg)
+        }
+        Opcode::StructAtomicGet => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_get)
+        }
+        Opcode::StructAtomicGetS => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_get_s)
+        }
+        Opcode::StructAtomicGetU => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_get_u)
+        }
+        Opcode::StructAtomicSet => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_set)
+        }
+        Opcode::StructAtomicRmwAdd => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_rmw_add)
+        }
+        Opcode::StructAtomicRmwSub => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_rmw_sub)
+        }
+        Opcode::StructAtomicRmwAnd => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_rmw_and)
+        }
+        Opcode::StructAtomicRmwOr => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_rmw_or)
+        }
+        Opcode::StructAtomicRmwXor => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_rmw_xor)
+        }
+        Opcode::StructAtomicRmwXchg => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_rmw_xchg)
+        }
+        Opcode::StructAtomicRmwCmpxchg => {
+            simple_arguments!(ordering: isa::AtomicOrdering, struct_type: TypeIdx, field: module::FieldIdx => struct_atomic_rmw_cmpxchg)
+        }
+        Opcode::ArrayAtomicGet => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_get)
+        }
+        Opcode::ArrayAtomicGetS => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_get_s)
+        }
+        Opcode::ArrayAtomicGetU => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_get_u)
+        }
+        Opcode::ArrayAtomicSet => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_set)
+        }
+        Opcode::ArrayAtomicRmwAdd => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_rmw_add)
+        }
+        Opcode::ArrayAtomicRmwSub => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_rmw_sub)
+        }
+        Opcode::ArrayAtomicRmwAnd => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_rmw_and)
+        }
+        Opcode::ArrayAtomicRmwOr => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_rmw_or)
+        }
+        Opcode::ArrayAtomicRmwXor => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_rmw_xor)
+        }
+        Opcode::ArrayAtomicRmwXchg => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_rmw_xchg)
+        }
+        Opcode::ArrayAtomicRmwCmpxchg => {
+            simple_arguments!(ordering: isa::AtomicOrdering, array_type: TypeIdx => array_atomic_rmw_cmpxchg)
+        }
     };
 
     Ok((input, parser))
@@ -0,0 +1,156 @@
+use crate::{
+    error::{self, ErrorCause, ErrorSource},
+    index::Index as _,
+    input::Result,
+    isa::LabelIdx,
+    module::TagIdx,
+    values::VectorIter,
+};
+
+/// A handler clause of a [`resume`] or [`resume_throw`] instruction, introduced as part of the
+/// [stack switching proposal].
+///
+/// [`resume`]: crate::isa::ParseInstr::resume
+/// [`resume_throw`]: crate::isa::ParseInstr::resume_throw
+/// [stack switching proposal]: https://github.com/WebAssembly/stack-switching
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ResumeHandler {
+    /// **`(on $tag $label)`** branches to the `label` when the continuation is suspended with the
+    /// given `tag`.
+    OnLabel {
+        #[allow(missing_docs)]
+        tag: TagIdx,
+        #[allow(missing_docs)]
+        label: LabelIdx,
+    },
+    /// **`(on $tag switch)`** allows the continuation to [`switch`] to another continuation with
+    /// the given `tag`.
+    ///
+    /// [`switch`]: crate::isa::ParseInstr::switch
+    OnSwitch {
+        #[allow(missing_docs)]
+        tag: TagIdx,
+    },
+}
+
+impl ResumeHandler {
+    /// Gets the tag that this handler applies to.
+    #[inline]
+    pub const fn tag(&self) -> TagIdx {
+        match self {
+            Self::OnLabel { tag, .. } | Self::OnSwitch { tag } => *tag,
+        }
+    }
+
+    /// Gets the label that is branched to when the continuation is suspended, or `None` if this
+    /// is a **`switch`** handler.
+    #[inline]
+    pub const fn label(&self) -> Option<LabelIdx> {
+        match self {
+            Self::OnLabel { label, .. } => Some(*label),
+            Self::OnSwitch { .. } => None,
+        }
+    }
+
+    #[allow(missing_docs)]
+    pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let (input, kind) = if let Some((first, remaining)) = input.split_first() {
+            (remaining, *first)
+        } else {
+            return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                error::ErrorKind::OneOf,
+                ErrorCause::InvalidTag(error::InvalidTag::ResumeHandler(None)),
+            )));
+        };
+
+        match kind {
+            0 => {
+                let (input, tag) = TagIdx::parse(input)?;
+                let (input, label) = LabelIdx::parse(input)?;
+                Ok((input, Self::OnLabel { tag, label }))
+            }
+            1 => {
+                let (input, tag) = TagIdx::parse(input)?;
+                Ok((input, Self::OnSwitch { tag }))
+            }
+            _ => Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                error::ErrorKind::OneOf,
+                ErrorCause::InvalidTag(error::InvalidTag::ResumeHandler(Some(kind))),
+            ))),
+        }
+    }
+}
+
+impl core::fmt::Display for ResumeHandler {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OnLabel { tag, label } => write!(f, "(on {tag} {label})"),
+            Self::OnSwitch { tag } => write!(f, "(on {tag} switch)"),
+        }
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`ResumeHandler::parse()`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct ResumeHandlerParser;
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], ResumeHandler, E> for ResumeHandlerParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, ResumeHandler, E> {
+        ResumeHandler::parse(input)
+    }
+}
+
+/// Parses the [`ResumeHandler`]s of a [`resume`] or [`resume_throw`] instruction.
+///
+/// [`resume`]: crate::isa::ParseInstr::resume
+/// [`resume_throw`]: crate::isa::ParseInstr::resume_throw
+#[derive(Clone)]
+#[must_use]
+pub struct ResumeHandlers<'a, E: ErrorSource<'a> = crate::error::Error<'a>> {
+    handlers: VectorIter<'a, ResumeHandler, E, ResumeHandlerParser>,
+}
+
+#[allow(missing_docs)]
+impl<'a, E: ErrorSource<'a>> ResumeHandlers<'a, E> {
+    pub fn with_input(input: &'a [u8]) -> Result<Self, E> {
+        Ok(Self {
+            handlers: VectorIter::with_parsed_length(input, ResumeHandlerParser)?,
+        })
+    }
+
+    pub fn finish(self) -> crate::Parsed<'a, (), E> {
+        self.handlers.finish().map(|(input, _)| (input, ()))
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> Iterator for ResumeHandlers<'a, E> {
+    type Item = Result<ResumeHandler, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.handlers.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.handlers.size_hint()
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> ExactSizeIterator for ResumeHandlers<'a, E> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.handlers.len()
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> core::fmt::Debug for ResumeHandlers<'a, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ResumeHandlers").finish_non_exhaustive()
+    }
+}
@@ -24,6 +24,7 @@ mod static_assert;
 mod tag;
 
 pub mod error;
+pub mod features;
 pub mod index;
 pub mod input;
 pub mod isa;
@@ -6,19 +6,45 @@ pub mod custom;
 pub mod preamble;
 
 mod binary;
+mod code_sec;
 mod core_indices;
+mod data_count_sec;
+mod data_sec;
+mod elem_sec;
+mod export_sec;
+mod func_sec;
+mod global_sec;
 mod import_sec;
+mod mem_sec;
 mod module_section;
 mod module_section_sequence;
+mod start_sec;
+mod table_sec;
+mod tag_sec;
 mod type_sec;
 
 pub use binary::Module;
+pub use code_sec::{CodeSec, FuncBody, FuncBodyParser, Locals, LocalsParser};
 pub use core_indices::{
-    DataIdx, ElemIdx, FuncIdx, GlobalIdx, LabelIdx, LocalIdx, MemIdx, TableIdx, TagIdx, TypeIdx,
+    DataIdx, ElemIdx, FieldIdx, FuncIdx, GlobalIdx, LabelIdx, LocalIdx, MemIdx, TableIdx, TagIdx,
+    TypeIdx,
 };
-pub use import_sec::{Import, ImportDesc, ImportSec};
+pub use data_count_sec::data_count_sec;
+pub use data_sec::{DataMode, DataSec, DataSegment, DataSegmentParser};
+pub use elem_sec::{ElemInit, ElemMode, ElemSec, ElemSegment, ElemSegmentParser};
+pub use export_sec::{Export, ExportDesc, ExportParser, ExportSec};
+pub use func_sec::FuncSec;
+pub use global_sec::{Global, GlobalParser, GlobalSec};
+pub use import_sec::{
+    GroupedImportParser, Import, ImportDesc, ImportEntry, ImportEntryParser, ImportGroup,
+    ImportParser, ImportSec, Imports,
+};
+pub use mem_sec::MemSec;
 pub use module_section::{ModuleSection, ModuleSectionId};
 pub use module_section_sequence::{
     module_section_sequence, module_section_sequence_with_unknown, ModuleSectionOrder,
 };
+pub use start_sec::start_sec;
+pub use table_sec::{Table, TableParser, TableSec};
+pub use tag_sec::TagSec;
 pub use type_sec::TypeSec;
@@ -1,10 +1,19 @@
 use crate::{
     error::ErrorSource,
-    input::Result,
-    module::{self, custom::CustomSection, preamble, ModuleSection, ModuleSectionOrder},
+    features::WasmFeatures,
+    input::{AsInput as _, Result},
+    module::{
+        self, custom::CustomSection, module_section_sequence, preamble, ImportDesc, ModuleSection,
+        ModuleSectionOrder,
+    },
     section::Section,
 };
 
+#[inline]
+fn skip_custom_section<E>(_: CustomSection<'_>, _: Option<ModuleSectionOrder>) -> Result<(), E> {
+    Ok(())
+}
+
 /// Represents a module in the [WebAssembly binary format].
 ///
 /// [WebAssembly binary format]: https://webassembly.github.io/spec/core/binary/modules.html#binary-module
@@ -14,6 +23,20 @@ use crate::{
 pub struct Module<'a> {
     pub type_sec: module::TypeSec<'a>,
     pub import_sec: module::ImportSec<'a>,
+    pub func_sec: module::FuncSec<'a>,
+    pub table_sec: module::TableSec<'a>,
+    pub mem_sec: module::MemSec<'a>,
+    pub tag_sec: module::TagSec<'a>,
+    pub global_sec: module::GlobalSec<'a>,
+    pub export_sec: module::ExportSec<'a>,
+    pub start: Option<module::FuncIdx>,
+    pub elem_sec: module::ElemSec<'a>,
+    pub code_sec: module::CodeSec<'a>,
+    pub data_sec: module::DataSec<'a>,
+    /// The number of data segments specified by the *data count section*, if it was present.
+    ///
+    /// If present, this is checked against the number of segments in the *data section*.
+    pub data_count: Option<u32>,
 }
 
 impl<'a> Module<'a> {
@@ -30,12 +53,87 @@ impl<'a> Module<'a> {
                 ModuleSection::Custom(custom_sec) => custom_f(custom_sec, order)?,
                 ModuleSection::Type(type_sec) => module.type_sec = type_sec,
                 ModuleSection::Import(import_sec) => module.import_sec = import_sec,
+                ModuleSection::Func(func_sec) => module.func_sec = func_sec,
+                ModuleSection::Table(table_sec) => module.table_sec = table_sec,
+                ModuleSection::Mem(mem_sec) => module.mem_sec = mem_sec,
+                ModuleSection::Tag(tag_sec) => module.tag_sec = tag_sec,
+                ModuleSection::Global(global_sec) => module.global_sec = global_sec,
+                ModuleSection::Export(export_sec) => module.export_sec = export_sec,
+                ModuleSection::Start(start) => module.start = Some(start),
+                ModuleSection::Elem(elem_sec) => module.elem_sec = elem_sec,
+                ModuleSection::Code(code_sec) => module.code_sec = code_sec,
+                ModuleSection::Data(data_sec) => {
+                    data_sec.check_data_count(module.data_count)?;
+                    module.data_sec = data_sec;
+                }
+                ModuleSection::DataCount(count) => module.data_count = Some(count),
             }
 
             Ok(())
         }
     }
 
+    /// Checks that a *data count section* is not present without a corresponding *data
+    /// section*, which is treated as containing zero data segments.
+    fn check_missing_data_sec<E: ErrorSource<'a>>(self, binary: &'a [u8]) -> Result<Self, E> {
+        match self.data_count {
+            Some(expected) if expected != 0 && self.data_sec.count() == 0 => {
+                Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                    &binary[binary.len()..],
+                    crate::error::ErrorKind::Verify,
+                    crate::error::ErrorCause::DataCount {
+                        expected,
+                        actual: 0,
+                    },
+                )))
+            }
+            _ => Ok(self),
+        }
+    }
+
+    /// Checks that more than one memory or table is only defined or imported if the
+    /// corresponding proposal is enabled.
+    fn check_index_spaces<E: ErrorSource<'a>>(&self, features: WasmFeatures) -> Result<(), E> {
+        if features.contains(WasmFeatures::MULTI_MEMORY | WasmFeatures::REFERENCE_TYPES) {
+            return Ok(());
+        }
+
+        let mut mem_count = self.mem_sec.count();
+        let mut table_count = self.table_sec.count();
+        for result in self.import_sec.iter_contents::<E>() {
+            match result?.desc {
+                ImportDesc::Memory(_) => mem_count += 1,
+                ImportDesc::Table(_) => table_count += 1,
+                _ => (),
+            }
+        }
+
+        // Point at the section that defines the memories or tables, if there is one
+        let input = |count: usize, section: &'a [u8]| {
+            if count > 0 {
+                section
+            } else {
+                self.import_sec.as_input()
+            }
+        };
+
+        if mem_count > 1 {
+            features.check(
+                input(self.mem_sec.count(), self.mem_sec.as_input()),
+                WasmFeatures::MULTI_MEMORY,
+            )?;
+        }
+
+        if table_count > 1 {
+            features.check(
+                input(self.table_sec.count(), self.table_sec.as_input()),
+                WasmFeatures::REFERENCE_TYPES,
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Parses a module from its encoding in the WebAssembly binary format, using the given
     /// closures to handle custom and unrecognized sections.
     ///
@@ -66,7 +164,7 @@ impl<'a> Module<'a> {
             Self::parse_module_section(&mut module, custom),
             unknown,
         )?;
-        Ok(module)
+        module.check_missing_data_sec(binary)
     }
 
     /// Parses a module from its encoding in the WebAssembly binary format, passing custom
@@ -87,7 +185,7 @@ impl<'a> Module<'a> {
         let (input, ()) = preamble::parse(binary)?;
         let mut module = Self::default();
         module::module_section_sequence(input, Self::parse_module_section(&mut module, custom))?;
-        Ok(module)
+        module.check_missing_data_sec(binary)
     }
 
     /// Parses a module from its encoding in the WebAssembly binary format, ignoring custom sections.
@@ -95,14 +193,38 @@ impl<'a> Module<'a> {
     /// To process custom sections, use the [`Module::parse_with_custom_sections()`] method
     /// instead.
     pub fn parse<E: ErrorSource<'a>>(binary: &'a [u8]) -> Result<Self, E> {
-        #[inline]
-        fn skip_custom_section<E>(
-            _: CustomSection<'_>,
-            _: Option<ModuleSectionOrder>,
-        ) -> Result<(), E> {
-            Ok(())
-        }
-
         Self::parse_with_custom_sections(binary, skip_custom_section)
     }
+
+    /// Parses a module from its encoding in the WebAssembly binary format, ignoring custom
+    /// sections, and failing if the module uses any proposals that are not enabled in the given
+    /// set of `features`.
+    ///
+    /// The contents of the *type*, *import*, *table*, *memory*, *tag*, and *global* sections are
+    /// checked when they are iterated over or parsed, as described in the documentation for
+    /// [`ModuleSection::interpret_section_with_features()`]. Defining or importing more than one
+    /// memory requires the [multi-memory proposal](WasmFeatures::MULTI_MEMORY), while more than
+    /// one table requires the [reference types proposal](WasmFeatures::REFERENCE_TYPES).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with an [`ErrorCause::FeatureDisabled`] if the module requires a disabled
+    /// proposal, or any other error that [`Module::parse()`] would return.
+    ///
+    /// [`ErrorCause::FeatureDisabled`]: crate::error::ErrorCause::FeatureDisabled
+    pub fn parse_with_features<E: ErrorSource<'a>>(
+        binary: &'a [u8],
+        features: WasmFeatures,
+    ) -> Result<Self, E> {
+        let (input, ()) = preamble::parse(binary)?;
+        let mut module = Self::default();
+        module_section_sequence::sequence_with_features(
+            input,
+            features,
+            Self::parse_module_section(&mut module, skip_custom_section),
+            module_section_sequence::no_unknown_section,
+        )?;
+        module.check_index_spaces(features)?;
+        module.check_missing_data_sec(binary)
+    }
 }
@@ -0,0 +1,306 @@
+use crate::{
+    error::{self, AddCause as _, ErrorCause, ErrorKind, ErrorSource},
+    isa,
+    types::ValType,
+    values::VectorIter,
+};
+use nom::ToUsize;
+
+/// Provides a [`nom::Parser`] implementation for the [local declarations] of a [`FuncBody`],
+/// which are encoded as a run-length encoded sequence of [`ValType`]s.
+///
+/// Each local declaration is parsed as a count and a [`ValType`]. An error is returned if the
+/// total number of local variables exceeds [`u32::MAX`].
+///
+/// [local declarations]: https://webassembly.github.io/spec/core/binary/modules.html#binary-local
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LocalsParser {
+    total: u32,
+}
+
+impl LocalsParser {
+    /// Gets the total number of local variables declared so far.
+    #[inline]
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], (u32, ValType), E> for LocalsParser {
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, (u32, ValType), E> {
+        let start = input;
+        let (input, count) = crate::values::leb128_u32(input)?;
+
+        self.total = self.total.checked_add(count).ok_or_else(|| {
+            nom::Err::Failure(E::from_error_kind_and_cause(
+                start,
+                ErrorKind::TooLarge,
+                ErrorCause::LocalsOverflow,
+            ))
+        })?;
+
+        let (input, value_type) = ValType::parse(input)?;
+        Ok((input, (count, value_type)))
+    }
+}
+
+/// Type alias for an [`Iterator`] over the [local declarations] of a [`FuncBody`].
+///
+/// [local declarations]: https://webassembly.github.io/spec/core/binary/modules.html#binary-local
+pub type Locals<'a, E> = VectorIter<'a, (u32, ValType), E, LocalsParser>;
+
+/// Represents the [**`code`**] of a function defined in the [*code section*], which contains the
+/// function's local variables and its body.
+///
+/// The local declarations and instructions are parsed lazily.
+///
+/// [**`code`**]: https://webassembly.github.io/spec/core/binary/modules.html#binary-code
+/// [*code section*]: https://webassembly.github.io/spec/core/binary/modules.html#code-section
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[must_use]
+pub struct FuncBody<'a> {
+    contents: &'a [u8],
+}
+
+impl<'a> FuncBody<'a> {
+    /// Parses the size of a function body, and returns a [`FuncBody`] over its contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the size could not be parsed, or if the `input` is too small to
+    /// contain the function body.
+    pub fn parse<E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let (input, size) = crate::values::leb128_u32(input).add_cause(ErrorCause::FuncBodySize)?;
+
+        if let Some(contents) = input.get(..size.to_usize()) {
+            Ok((&input[size.to_usize()..], Self { contents }))
+        } else {
+            Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                ErrorKind::Eof,
+                ErrorCause::FuncBodyContents(error::LengthMismatch {
+                    expected: size,
+                    actual: input.len().try_into().unwrap_or(u32::MAX),
+                }),
+            )))
+        }
+    }
+
+    /// Returns an [`Iterator`] over the function's local variable declarations.
+    ///
+    /// Each item is a count and the [`ValType`] of that many local variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of local declarations could not be parsed.
+    pub fn locals<E: ErrorSource<'a>>(&self) -> crate::input::Result<Locals<'a, E>, E> {
+        Locals::with_parsed_length(self.contents, LocalsParser::default())
+    }
+
+    /// Gets the bytes containing the function's instructions, which are placed after its local
+    /// variable declarations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the local declarations could not be parsed.
+    pub fn instructions<E: ErrorSource<'a>>(&self) -> crate::input::Result<&'a [u8], E> {
+        self.locals()?
+            .finish()
+            .map(|(instructions, _)| instructions)
+    }
+
+    /// Parses the function's [**`expr`**](isa::expr) with the given
+    /// [`ParseInstr`](isa::ParseInstr) implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local declarations or the expression could not be parsed, or if
+    /// there were bytes remaining after the final **`end`** instruction.
+    pub fn parse_expr_with<P, E>(&self, parser: P) -> crate::input::Result<P, E>
+    where
+        P: isa::ParseInstr<'a, E>,
+        E: ErrorSource<'a>,
+    {
+        let (remaining, parser) = isa::expr(self.instructions()?, parser)?;
+        Self::expect_end(remaining, parser)
+    }
+
+    /// Parses the function's [**`expr`**](isa::expr) with the given
+    /// [`ParseBranchHint`](isa::ParseBranchHint) implementation, passing each of the
+    /// [`BranchHint`]s for the function before the [**`br_if`**] or [**`if`**] instruction it
+    /// applies to.
+    ///
+    /// The `hints` are usually obtained from a [`BranchHintSec`], and are matched to instructions
+    /// by their offsets relative to the start of the function body's contents. Hints that do not
+    /// refer to a **`br_if`** or **`if`** instruction are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local declarations, the expression, or the `hints` could not be
+    /// parsed, or if there were bytes remaining after the final **`end`** instruction.
+    ///
+    /// [`BranchHint`]: crate::module::custom::BranchHint
+    /// [`BranchHintSec`]: crate::module::custom::BranchHintSec
+    /// [**`br_if`**]: isa::ParseInstr::br_if
+    /// [**`if`**]: isa::ParseInstr::if
+    pub fn parse_expr_with_branch_hints<P, E>(
+        &self,
+        hints: &crate::module::custom::FuncBranchHints<'a>,
+        parser: P,
+    ) -> crate::input::Result<P, E>
+    where
+        P: isa::ParseBranchHint<'a, E>,
+        E: ErrorSource<'a>,
+    {
+        let (remaining, parser) =
+            isa::expr_with_branch_hints(self.contents, self.instructions()?, hints, parser)?;
+        Self::expect_end(remaining, parser)
+    }
+
+    /// Checks that the function's local variables and [**`expr`**](isa::expr) are well-typed,
+    /// given the index of the function's type.
+    ///
+    /// The `validator` can be reused to validate other function bodies in the same module.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with an [`ErrorCause::FuncValidation`] if an instruction is not
+    /// well-typed, or an error if the function body could not be parsed.
+    pub fn validate<C, H, E>(
+        &self,
+        signature: crate::module::TypeIdx,
+        validator: &mut isa::FuncValidator<'_, C, H>,
+    ) -> crate::input::Result<(), E>
+    where
+        C: isa::ModuleContext + ?Sized,
+        H: crate::storage::Heap,
+        E: ErrorSource<'a>,
+    {
+        let (remaining, ()) = isa::validate_func_body(self.contents, signature, validator)?;
+        Self::expect_end(remaining, ())
+    }
+
+    fn expect_end<P, E: ErrorSource<'a>>(
+        remaining: &'a [u8],
+        parser: P,
+    ) -> crate::input::Result<P, E> {
+        if remaining.is_empty() {
+            Ok(parser)
+        } else {
+            Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                remaining,
+                ErrorKind::Eof,
+                ErrorCause::FuncBodyEnd,
+            )))
+        }
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for FuncBody<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.contents
+    }
+}
+
+impl core::fmt::Debug for FuncBody<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct DebugLocals<'b, 'a>(&'b FuncBody<'a>);
+
+        impl core::fmt::Debug for DebugLocals<'_, '_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut list = f.debug_list();
+                match self.0.locals::<error::Error>() {
+                    Ok(locals) => {
+                        for result in locals {
+                            match result {
+                                Ok(local) => list.entry(&local),
+                                Err(err) => list.entry(&err),
+                            };
+                        }
+                    }
+                    Err(err) => {
+                        list.entry(&err);
+                    }
+                }
+                list.finish()
+            }
+        }
+
+        let mut s = f.debug_struct("FuncBody");
+        s.field("locals", &DebugLocals(self));
+        if let Ok(instructions) = self.instructions::<error::Error>() {
+            s.field("instructions", &crate::hex::Bytes(instructions));
+        }
+        s.finish()
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`FuncBody::parse()`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct FuncBodyParser;
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], FuncBody<'a>, E> for FuncBodyParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, FuncBody<'a>, E> {
+        FuncBody::parse(input)
+    }
+}
+
+/// Represents the [*code section*].
+///
+/// This contains the local variables and bodies of each function defined in the
+/// [*function section*](crate::module::FuncSec).
+///
+/// [*code section*]: https://webassembly.github.io/spec/core/binary/modules.html#code-section
+#[derive(Clone, Copy, Default)]
+#[must_use]
+pub struct CodeSec<'a> {
+    count: u32,
+    bodies: &'a [u8],
+}
+
+impl<'a> CodeSec<'a> {
+    /// Parses a *code section* from a section's contents.
+    pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<Self, E> {
+        let (bodies, count) = crate::values::vector_length(contents)?;
+        Ok(Self { count, bodies })
+    }
+
+    /// The expected number of [`FuncBody`]s within the section.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over each [`FuncBody`] within the section.
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`FuncBody`].
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(
+        &self,
+    ) -> VectorIter<'a, FuncBody<'a>, E, FuncBodyParser> {
+        VectorIter::new(self.count, self.bodies, FuncBodyParser).expect_eof()
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for CodeSec<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.bodies
+    }
+}
+
+impl core::fmt::Debug for CodeSec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(body) => list.entry(&body),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
@@ -73,4 +73,12 @@ crate::index::definitions! {
     /// [*tag section*]: https://webassembly.github.io/exception-handling/core/binary/modules.html#tag-section
     /// [exception handling proposal]: https://github.com/WebAssembly/exception-handling
     struct TagIdx = "tag";
+
+    /// A [**`fieldidx`**] refers to a field of a [structure type], introduced as part of the
+    /// [garbage collection proposal].
+    ///
+    /// [**`fieldidx`**]: https://webassembly.github.io/gc/core/binary/modules.html#binary-fieldidx
+    /// [structure type]: https://webassembly.github.io/gc/core/syntax/types.html#aggregate-types
+    /// [garbage collection proposal]: https://github.com/WebAssembly/gc
+    struct FieldIdx = "field";
 }
@@ -8,6 +8,13 @@ use crate::{
     section::Section,
 };
 
+mod branch_hint;
+
+pub use branch_hint::{
+    BranchHint, BranchHintParser, BranchHintSec, BranchLikelihood, FuncBranchHints,
+    FuncBranchHintsParser, InvalidBranchHint,
+};
+
 /// Represents a [*custom section*] within a [WebAssembly module].
 ///
 /// [*custom section*]: https://webassembly.github.io/spec/core/appendix/custom.html
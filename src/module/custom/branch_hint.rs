@@ -0,0 +1,298 @@
+use crate::{
+    error::{self, AddCause as _, ErrorCause, ErrorKind, ErrorSource},
+    index::Index as _,
+    module::{custom::CustomSection, FuncIdx},
+    values::VectorIter,
+};
+use nom::ToUsize;
+
+/// Describes why a [`BranchHint`] could not be parsed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidBranchHint {
+    /// The instruction offset of the hint could not be parsed.
+    Offset,
+    /// The size of the hint was not `1`, or could not be parsed.
+    Size(Option<u32>),
+    /// The hint value was not `0` or `1`, or was missing.
+    Value(Option<u8>),
+}
+
+crate::static_assert::check_size!(InvalidBranchHint, <= 8);
+
+impl core::fmt::Display for InvalidBranchHint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Offset => f.write_str("expected instruction offset for branch hint"),
+            Self::Size(None) => f.write_str("expected size of branch hint"),
+            Self::Size(Some(size)) => {
+                write!(f, "expected branch hint size of 1 byte, but got {size}")
+            }
+            Self::Value(None) => f.write_str("expected branch hint value"),
+            Self::Value(Some(value)) => write!(f, "{value:#04X} is not a valid branch hint"),
+        }
+    }
+}
+
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidBranchHint {}
+
+/// Indicates whether a branch is likely to be taken.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum BranchLikelihood {
+    /// The branch is not likely to be taken, encoded as `0`.
+    Unlikely,
+    /// The branch is likely to be taken, encoded as `1`.
+    Likely,
+}
+
+impl core::fmt::Display for BranchLikelihood {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Unlikely => "unlikely",
+            Self::Likely => "likely",
+        })
+    }
+}
+
+/// A hint indicating whether the branch of the [**`br_if`**] or [**`if`**] instruction at a given
+/// offset is likely to be taken.
+///
+/// [**`br_if`**]: crate::isa::ParseInstr::br_if
+/// [**`if`**]: crate::isa::ParseInstr::if
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct BranchHint {
+    /// The byte offset of the instruction, relative to the start of the function body's
+    /// [local declarations].
+    ///
+    /// [local declarations]: crate::module::FuncBody::locals
+    pub offset: u32,
+    #[allow(missing_docs)]
+    pub likelihood: BranchLikelihood,
+}
+
+fn hint_error<'a, E: ErrorSource<'a>>(input: &'a [u8], reason: InvalidBranchHint) -> nom::Err<E> {
+    nom::Err::Failure(E::from_error_kind_and_cause(
+        input,
+        ErrorKind::Verify,
+        ErrorCause::BranchHint(reason),
+    ))
+}
+
+impl BranchHint {
+    #[allow(missing_docs)]
+    pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let (input, offset) = crate::values::leb128_u32(input)
+            .add_cause(ErrorCause::BranchHint(InvalidBranchHint::Offset))?;
+
+        let (input, size) = crate::values::leb128_u32(input)
+            .add_cause(ErrorCause::BranchHint(InvalidBranchHint::Size(None)))?;
+
+        if size != 1 {
+            return Err(hint_error(input, InvalidBranchHint::Size(Some(size))));
+        }
+
+        let likelihood = match input.first() {
+            Some(0) => BranchLikelihood::Unlikely,
+            Some(1) => BranchLikelihood::Likely,
+            bad => return Err(hint_error(input, InvalidBranchHint::Value(bad.copied()))),
+        };
+
+        Ok((&input[1..], Self { offset, likelihood }))
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`BranchHint::parse()`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct BranchHintParser;
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], BranchHint, E> for BranchHintParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, BranchHint, E> {
+        BranchHint::parse(input)
+    }
+}
+
+/// The [`BranchHint`]s for the instructions of a single function, sorted by their offsets.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct FuncBranchHints<'a> {
+    /// The function that the hints apply to.
+    pub func: FuncIdx,
+    count: u32,
+    hints: &'a [u8],
+}
+
+impl<'a> FuncBranchHints<'a> {
+    /// Parses a function index followed by a vector of [`BranchHint`]s.
+    pub fn parse<E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let (input, func) = FuncIdx::parse(input)?;
+        let (hints, count) = crate::values::vector_length(input)?;
+        let (input, ()) = crate::values::sequence(hints, count, |input| {
+            BranchHint::parse(input).map(|(input, _)| (input, ()))
+        })?;
+
+        Ok((
+            input,
+            Self {
+                func,
+                count,
+                hints: &hints[..hints.len() - input.len()],
+            },
+        ))
+    }
+
+    /// The number of [`BranchHint`]s for the function.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over each [`BranchHint`] for the function.
+    #[inline]
+    pub fn iter<E: ErrorSource<'a>>(&self) -> VectorIter<'a, BranchHint, E, BranchHintParser> {
+        VectorIter::new(self.count, self.hints, BranchHintParser).expect_eof()
+    }
+}
+
+impl core::fmt::Debug for FuncBranchHints<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct DebugHints<'a, 'b>(&'b FuncBranchHints<'a>);
+
+        impl core::fmt::Debug for DebugHints<'_, '_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut list = f.debug_list();
+                for result in self.0.iter::<error::Error>() {
+                    match result {
+                        Ok(hint) => list.entry(&hint),
+                        Err(err) => list.entry(&err),
+                    };
+                }
+                list.finish()
+            }
+        }
+
+        f.debug_struct("FuncBranchHints")
+            .field("func", &self.func)
+            .field("hints", &DebugHints(self))
+            .finish()
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`FuncBranchHints::parse()`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct FuncBranchHintsParser;
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], FuncBranchHints<'a>, E>
+    for FuncBranchHintsParser
+{
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, FuncBranchHints<'a>, E> {
+        FuncBranchHints::parse(input)
+    }
+}
+
+/// Represents the [*branch hint section*], a [`CustomSection`] that indicates whether the branches
+/// of [**`br_if`**] and [**`if`**] instructions are likely to be taken.
+///
+/// Introduced as part of the [branch hinting proposal].
+///
+/// [*branch hint section*]: https://github.com/WebAssembly/branch-hinting/blob/main/proposals/branch-hinting/Overview.md
+/// [**`br_if`**]: crate::isa::ParseInstr::br_if
+/// [**`if`**]: crate::isa::ParseInstr::if
+/// [branch hinting proposal]: https://github.com/WebAssembly/branch-hinting
+#[derive(Clone, Copy, Default)]
+#[must_use]
+pub struct BranchHintSec<'a> {
+    count: u32,
+    funcs: &'a [u8],
+}
+
+impl<'a> BranchHintSec<'a> {
+    /// The [`name`](CustomSection::name) of the *branch hint section*.
+    pub const NAME: &'static str = "metadata.code.branch_hint";
+
+    /// Parses a *branch hint section* from a custom section's contents.
+    pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<Self, E> {
+        let (funcs, count) = crate::values::vector_length(contents)?;
+        Ok(Self { count, funcs })
+    }
+
+    /// Attempts to interpret the contents of a [`CustomSection`] as a *branch hint section*.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(_)` if the custom section's [`name`] is **not** [`BranchHintSec::NAME`], or
+    /// `Ok(Err(_))` if the contents could not be parsed.
+    ///
+    /// [`name`]: CustomSection::name
+    pub fn interpret_custom_section<'b, E: ErrorSource<'a>>(
+        section: &'b CustomSection<'a>,
+    ) -> Result<crate::input::Result<Self, E>, &'b CustomSection<'a>> {
+        if section.name == Self::NAME {
+            Ok(Self::parse(section.contents))
+        } else {
+            Err(section)
+        }
+    }
+
+    /// The expected number of functions with [`BranchHint`]s.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over the [`BranchHint`]s of each function.
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(
+        &self,
+    ) -> VectorIter<'a, FuncBranchHints<'a>, E, FuncBranchHintsParser> {
+        VectorIter::new(self.count, self.funcs, FuncBranchHintsParser).expect_eof()
+    }
+
+    /// Searches for the [`BranchHint`]s of the function with the given index.
+    ///
+    /// This performs a linear search over the contents of the section, and does not allocate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hints for a function could not be parsed before the hints for
+    /// `func` were found.
+    pub fn find<E: ErrorSource<'a>>(
+        &self,
+        func: FuncIdx,
+    ) -> crate::input::Result<Option<FuncBranchHints<'a>>, E> {
+        for result in self.iter_contents::<E>() {
+            let hints = result?;
+            if hints.func == func {
+                return Ok(Some(hints));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for BranchHintSec<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.funcs
+    }
+}
+
+impl core::fmt::Debug for BranchHintSec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(hints) => list.entry(&hints),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
@@ -0,0 +1,29 @@
+use crate::error::{AddCause as _, ErrorCause, ErrorKind, ErrorSource};
+
+/// Parses the contents of the [*data count section*], which contains the number of data segments
+/// in the [*data section*].
+///
+/// This section was introduced as part of the [bulk memory operations proposal], so that
+/// instructions such as `memory.init` and `data.drop` can be validated in a single pass.
+///
+/// # Errors
+///
+/// Returns an error if the count could not be parsed, or if there are bytes remaining after it.
+///
+/// [*data count section*]: https://webassembly.github.io/spec/core/binary/modules.html#data-count-section
+/// [*data section*]: https://webassembly.github.io/spec/core/binary/modules.html#data-section
+/// [bulk memory operations proposal]: https://github.com/WebAssembly/bulk-memory-operations
+pub fn data_count_sec<'a, E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<u32, E> {
+    let (remaining, count) =
+        crate::values::leb128_u32(contents).add_cause(ErrorCause::DataCountSec)?;
+
+    if remaining.is_empty() {
+        Ok(count)
+    } else {
+        Err(nom::Err::Failure(E::from_error_kind_and_cause(
+            remaining,
+            ErrorKind::Eof,
+            ErrorCause::DataCountSec,
+        )))
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{
+    error::{self, AddCause as _, ErrorCause, ErrorKind, ErrorSource},
+    index::Index as _,
+    input::AsInput as _,
+    isa,
+    module::MemIdx,
+    values::VectorIter,
+};
+use nom::ToUsize;
+
+/// Specifies how the contents of a [`DataSegment`] are used.
+///
+/// See the [WebAssembly specification] for more information.
+///
+/// [WebAssembly specification]: https://webassembly.github.io/spec/core/syntax/modules.html#data-segments
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum DataMode<'a> {
+    /// The contents can be copied into a memory with the `memory.init` instruction.
+    Passive,
+    /// The contents are copied into a memory when the module is instantiated.
+    Active {
+        /// The memory that the contents are copied into.
+        memory: MemIdx,
+        /// An [`Expr`](isa::Expr) that evaluates to the address in memory where the contents are
+        /// copied to.
+        offset: isa::Expr<'a>,
+    },
+}
+
+/// Represents a WebAssembly [**`data`**] segment defined in the [*data section*].
+///
+/// [**`data`**]: https://webassembly.github.io/spec/core/syntax/modules.html#data-segments
+/// [*data section*]: https://webassembly.github.io/spec/core/binary/modules.html#data-section
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct DataSegment<'a> {
+    /// Specifies how the contents are used.
+    pub mode: DataMode<'a>,
+    /// The contents of the data segment.
+    pub data: &'a [u8],
+}
+
+impl<'a> DataSegment<'a> {
+    const FLAG_PASSIVE: u32 = 0b01;
+    const FLAG_EXPLICIT_INDEX: u32 = 0b10;
+    pub(crate) const FLAGS_ALL: u32 = 0b11;
+
+    #[allow(missing_docs)]
+    pub fn parse<E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let start = input;
+        let (input, flags) = crate::values::leb128_u32(input).add_cause(
+            ErrorCause::InvalidFlags(error::InvalidFlags::DataSegment(None)),
+        )?;
+
+        let (input, mode) = match flags {
+            0 | Self::FLAG_EXPLICIT_INDEX => {
+                let (input, memory) = if flags & Self::FLAG_EXPLICIT_INDEX == 0 {
+                    (input, MemIdx(0))
+                } else {
+                    MemIdx::parse(input)?
+                };
+
+                let (input, offset) = isa::Expr::parse(input).add_cause(ErrorCause::DataOffset)?;
+                (input, DataMode::Active { memory, offset })
+            }
+            Self::FLAG_PASSIVE => (input, DataMode::Passive),
+            _ => {
+                return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                    &start[..start.len() - input.len()],
+                    ErrorKind::Verify,
+                    ErrorCause::InvalidFlags(error::InvalidFlags::DataSegment(
+                        core::num::NonZeroU32::new(flags),
+                    )),
+                )))
+            }
+        };
+
+        let (input, length) = crate::values::vector_length(input)?;
+
+        if let Some(data) = input.get(..length.to_usize()) {
+            Ok((&input[length.to_usize()..], Self { mode, data }))
+        } else {
+            Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                ErrorKind::Eof,
+                ErrorCause::DataSegmentContents(error::LengthMismatch {
+                    expected: length,
+                    actual: input.len().try_into().unwrap_or(u32::MAX),
+                }),
+            )))
+        }
+    }
+}
+
+impl core::fmt::Debug for DataSegment<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DataSegment")
+            .field("mode", &self.mode)
+            .field("data", &crate::hex::Bytes(self.data))
+            .finish()
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`DataSegment::parse()`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct DataSegmentParser;
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], DataSegment<'a>, E> for DataSegmentParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, DataSegment<'a>, E> {
+        DataSegment::parse(input)
+    }
+}
+
+/// Represents the [*data section*].
+///
+/// This corresponds to the [**datas** component] of a WebAssembly module.
+///
+/// [*data section*]: https://webassembly.github.io/spec/core/binary/modules.html#data-section
+/// [**datas** component]: https://webassembly.github.io/spec/core/syntax/modules.html#data-segments
+#[derive(Clone, Copy, Default)]
+#[must_use]
+pub struct DataSec<'a> {
+    count: u32,
+    segments: &'a [u8],
+}
+
+impl<'a> DataSec<'a> {
+    /// Parses a *data section* from a section's contents.
+    pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<Self, E> {
+        let (segments, count) = crate::values::vector_length(contents)?;
+        Ok(Self { count, segments })
+    }
+
+    /// The expected number of [`DataSegment`]s within the section.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Checks that the number of [`DataSegment`]s within the section matches the count specified
+    /// by the [*data count section*], if one was present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with an [`ErrorCause::DataCount`] if the counts do not match.
+    ///
+    /// [*data count section*]: crate::module::data_count_sec
+    pub fn check_data_count<E: ErrorSource<'a>>(
+        &self,
+        data_count: Option<u32>,
+    ) -> crate::input::Result<(), E> {
+        match data_count {
+            Some(expected) if expected != self.count => {
+                Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                    self.as_input(),
+                    ErrorKind::Verify,
+                    ErrorCause::DataCount {
+                        expected,
+                        actual: self.count,
+                    },
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns an [`Iterator`] over each [`DataSegment`] within the section.
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`DataSegment`].
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(
+        &self,
+    ) -> VectorIter<'a, DataSegment<'a>, E, DataSegmentParser> {
+        VectorIter::new(self.count, self.segments, DataSegmentParser).expect_eof()
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for DataSec<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.segments
+    }
+}
+
+impl core::fmt::Debug for DataSec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(segment) => list.entry(&segment),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
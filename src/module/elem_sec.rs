@@ -0,0 +1,314 @@
+use crate::{
+    error::{self, AddCause as _, ErrorCause, ErrorSource},
+    index::{Index as _, IndexParser, IndexVectorParser},
+    isa,
+    module::{FuncIdx, TableIdx},
+    types::RefType,
+    values::VectorIter,
+};
+use nom::ToUsize;
+
+/// Specifies how the elements of an [`ElemSegment`] are used.
+///
+/// See the [WebAssembly specification] for more information.
+///
+/// [WebAssembly specification]: https://webassembly.github.io/spec/core/syntax/modules.html#element-segments
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ElemMode<'a> {
+    /// The elements can be copied into a table with the `table.init` instruction.
+    Passive,
+    /// The elements are copied into a table when the module is instantiated.
+    Active {
+        /// The table that the elements are copied into.
+        table: TableIdx,
+        /// An [`Expr`](isa::Expr) that evaluates to the index into the table where elements are
+        /// copied to.
+        offset: isa::Expr<'a>,
+    },
+    /// The elements are not available at runtime, and only serve to forward-declare references
+    /// that are used in instructions like `ref.func`.
+    Declarative,
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+enum ElemInitKind {
+    Funcs,
+    Exprs(RefType),
+}
+
+/// The elements of an [`ElemSegment`], encoded either as a vector of [`FuncIdx`] or as a vector
+/// of [`Expr`](isa::Expr)essions.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+#[must_use]
+pub struct ElemInit<'a> {
+    kind: ElemInitKind,
+    count: u32,
+    contents: &'a [u8],
+}
+
+impl<'a> ElemInit<'a> {
+    /// The type of the elements.
+    #[inline]
+    pub fn element_type(&self) -> RefType {
+        match self.kind {
+            ElemInitKind::Funcs => RefType::FUNCREF,
+            ElemInitKind::Exprs(element_type) => element_type,
+        }
+    }
+
+    /// The expected number of elements.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over the [`FuncIdx`] of each element, or [`None`] if the elements
+    /// are encoded as expressions.
+    pub fn func_indices<E: ErrorSource<'a>>(&self) -> Option<IndexVectorParser<'a, FuncIdx, E>> {
+        match self.kind {
+            ElemInitKind::Funcs => Some(IndexVectorParser::new(
+                self.count,
+                self.contents,
+                IndexParser,
+            )),
+            ElemInitKind::Exprs(_) => None,
+        }
+    }
+
+    /// Returns an [`Iterator`] over the [`Expr`](isa::Expr) of each element, or [`None`] if the
+    /// elements are encoded as function indices.
+    ///
+    /// The instructions of each expression can be parsed with [`isa::Expr::parse_with()`].
+    pub fn exprs<E: ErrorSource<'a>>(
+        &self,
+    ) -> Option<VectorIter<'a, isa::Expr<'a>, E, isa::ExprParser>> {
+        match self.kind {
+            ElemInitKind::Funcs => None,
+            ElemInitKind::Exprs(_) => {
+                Some(VectorIter::new(self.count, self.contents, isa::ExprParser))
+            }
+        }
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for ElemInit<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.contents
+    }
+}
+
+impl core::fmt::Debug for ElemInit<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        struct Elements<'b, 'a>(&'b ElemInit<'a>);
+
+        impl core::fmt::Debug for Elements<'_, '_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut list = f.debug_list();
+                if let Some(indices) = self.0.func_indices::<error::Error>() {
+                    for result in indices {
+                        match result {
+                            Ok(index) => list.entry(&index),
+                            Err(err) => list.entry(&err),
+                        };
+                    }
+                } else if let Some(exprs) = self.0.exprs::<error::Error>() {
+                    for result in exprs {
+                        match result {
+                            Ok(expr) => list.entry(&expr),
+                            Err(err) => list.entry(&err),
+                        };
+                    }
+                }
+                list.finish()
+            }
+        }
+
+        f.debug_struct("ElemInit")
+            .field("element_type", &self.element_type())
+            .field("elements", &Elements(self))
+            .finish()
+    }
+}
+
+/// Represents a WebAssembly [**`elem`**] segment defined in the [*element section*].
+///
+/// [**`elem`**]: https://webassembly.github.io/spec/core/syntax/modules.html#element-segments
+/// [*element section*]: https://webassembly.github.io/spec/core/binary/modules.html#element-section
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct ElemSegment<'a> {
+    /// Specifies how the elements are used.
+    pub mode: ElemMode<'a>,
+    /// The elements of the segment.
+    pub elements: ElemInit<'a>,
+}
+
+impl<'a> ElemSegment<'a> {
+    const FLAG_PASSIVE_OR_DECLARATIVE: u32 = 0b001;
+    const FLAG_EXPLICIT_INDEX_OR_DECLARATIVE: u32 = 0b010;
+    const FLAG_EXPRS: u32 = 0b100;
+    pub(crate) const FLAGS_ALL: u32 = 0b111;
+
+    /// Parses an element segment, which may be encoded in one of eight different ways depending
+    /// on its flags.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the flags were invalid, or if any of the segment's components could
+    /// not be parsed.
+    pub fn parse<E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let start = input;
+        let (input, flags) = crate::values::leb128_u32(input).add_cause(
+            ErrorCause::InvalidFlags(error::InvalidFlags::ElemSegment(None)),
+        )?;
+
+        if flags & !Self::FLAGS_ALL != 0 {
+            return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                &start[..start.len() - input.len()],
+                error::ErrorKind::Verify,
+                ErrorCause::InvalidFlags(error::InvalidFlags::ElemSegment(
+                    core::num::NonZeroU32::new(flags),
+                )),
+            )));
+        }
+
+        let (input, mode) = if flags & Self::FLAG_PASSIVE_OR_DECLARATIVE == 0 {
+            let (input, table) = if flags & Self::FLAG_EXPLICIT_INDEX_OR_DECLARATIVE == 0 {
+                (input, TableIdx(0))
+            } else {
+                TableIdx::parse(input)?
+            };
+
+            let (input, offset) = isa::Expr::parse(input).add_cause(ErrorCause::ElemOffset)?;
+            (input, ElemMode::Active { table, offset })
+        } else if flags & Self::FLAG_EXPLICIT_INDEX_OR_DECLARATIVE == 0 {
+            (input, ElemMode::Passive)
+        } else {
+            (input, ElemMode::Declarative)
+        };
+
+        // Only the encodings for active segments with an implicit table index omit the
+        // `elemkind` or `reftype`
+        let has_element_type = flags & 0b011 != 0;
+
+        let (input, kind) = if flags & Self::FLAG_EXPRS == 0 {
+            let input = if has_element_type {
+                match input.split_first() {
+                    Some((0, input)) => input,
+                    bad => {
+                        return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                            input,
+                            error::ErrorKind::Tag,
+                            ErrorCause::InvalidTag(error::InvalidTag::ElemKind(
+                                bad.map(|(kind, _)| *kind),
+                            )),
+                        )))
+                    }
+                }
+            } else {
+                input
+            };
+
+            (input, ElemInitKind::Funcs)
+        } else if has_element_type {
+            let (input, element_type) = RefType::parse(input)?;
+            (input, ElemInitKind::Exprs(element_type))
+        } else {
+            (input, ElemInitKind::Exprs(RefType::FUNCREF))
+        };
+
+        let (contents, count) = crate::values::vector_length(input)?;
+        let mut elements = ElemInit {
+            kind,
+            count,
+            contents,
+        };
+
+        let remaining = match kind {
+            ElemInitKind::Funcs => {
+                VectorIter::<FuncIdx, E, _>::new(count, contents, IndexParser)
+                    .finish()?
+                    .0
+            }
+            ElemInitKind::Exprs(_) => {
+                VectorIter::<isa::Expr, E, _>::new(count, contents, isa::ExprParser)
+                    .finish()?
+                    .0
+            }
+        };
+
+        elements.contents = &contents[..contents.len() - remaining.len()];
+        Ok((remaining, Self { mode, elements }))
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`ElemSegment::parse()`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct ElemSegmentParser;
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], ElemSegment<'a>, E> for ElemSegmentParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, ElemSegment<'a>, E> {
+        ElemSegment::parse(input)
+    }
+}
+
+/// Represents the [*element section*].
+///
+/// This corresponds to the [**elems** component] of a WebAssembly module.
+///
+/// [*element section*]: https://webassembly.github.io/spec/core/binary/modules.html#element-section
+/// [**elems** component]: https://webassembly.github.io/spec/core/syntax/modules.html#element-segments
+#[derive(Clone, Copy, Default)]
+#[must_use]
+pub struct ElemSec<'a> {
+    count: u32,
+    segments: &'a [u8],
+}
+
+impl<'a> ElemSec<'a> {
+    /// Parses an *element section* from a section's contents.
+    pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<Self, E> {
+        let (segments, count) = crate::values::vector_length(contents)?;
+        Ok(Self { count, segments })
+    }
+
+    /// The expected number of [`ElemSegment`]s within the section.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over each [`ElemSegment`] within the section.
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`ElemSegment`].
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(
+        &self,
+    ) -> VectorIter<'a, ElemSegment<'a>, E, ElemSegmentParser> {
+        VectorIter::new(self.count, self.segments, ElemSegmentParser).expect_eof()
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for ElemSec<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.segments
+    }
+}
+
+impl core::fmt::Debug for ElemSec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(segment) => list.entry(&segment),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    error::{self, ErrorSource},
+    input::AsInput as _,
+    values::VectorIter,
+};
+use nom::ToUsize;
+
+mod export;
+mod export_desc;
+
+pub use export::{Export, ExportParser};
+pub use export_desc::ExportDesc;
+
+/// Represents the [*export section*].
+///
+/// This corresponds to the [**exports** component] of a WebAssembly module.
+///
+/// [*export section*]: https://webassembly.github.io/spec/core/binary/modules.html#export-section
+/// [**exports** component]: https://webassembly.github.io/spec/core/syntax/modules.html#exports
+#[derive(Clone, Copy, Default)]
+#[must_use]
+pub struct ExportSec<'a> {
+    count: u32,
+    exports: &'a [u8],
+}
+
+impl<'a> ExportSec<'a> {
+    /// Parses an [`Export`] section from a section's contents.
+    pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<Self, E> {
+        let (exports, count) = crate::values::vector_length(contents)?;
+        Ok(Self { count, exports })
+    }
+
+    /// The expected number of [`Export`]s within the section.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over each [`Export`] within the section.
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`Export`].
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(&self) -> VectorIter<'a, Export<'a>, E, ExportParser> {
+        VectorIter::new(self.count, self.exports, ExportParser).expect_eof()
+    }
+
+    /// Searches for the first [`Export`] with the given `name`, returning its [`ExportDesc`].
+    ///
+    /// This performs a linear search over the contents of the section, and does not allocate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an [`Export`] could not be parsed before one with the given `name` was
+    /// found.
+    pub fn find<E: ErrorSource<'a>>(
+        &self,
+        name: &str,
+    ) -> crate::input::Result<Option<ExportDesc>, E> {
+        for result in self.iter_contents::<E>() {
+            let export = result?;
+            if export.name == name {
+                return Ok(Some(export.desc));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Checks that the name of each [`Export`] within the section is unique, as required by the
+    /// [WebAssembly specification].
+    ///
+    /// In order to avoid allocations, this compares each export's name against the names of all
+    /// exports before it, so the time taken grows quadratically with the number of exports.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an [`Export`] could not be parsed, or if an [`Export`] has the same
+    /// name as one before it, in which case the error's cause is
+    /// [`ErrorCause::DuplicateExport`](error::ErrorCause::DuplicateExport).
+    ///
+    /// [WebAssembly specification]: https://webassembly.github.io/spec/core/valid/modules.html#modules
+    pub fn check_duplicate_names<E: ErrorSource<'a>>(&self) -> crate::input::Result<(), E> {
+        let mut exports = self.iter_contents::<E>();
+        let mut index = 0u32;
+
+        loop {
+            let start = exports.as_input();
+            let export = match exports.next() {
+                Some(result) => result?,
+                None => return Ok(()),
+            };
+
+            let mut previous = self.iter_contents::<E>().take(index.to_usize());
+            if previous.any(|other| matches!(other, Ok(other) if other.name == export.name)) {
+                return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                    start,
+                    error::ErrorKind::Verify,
+                    error::ErrorCause::DuplicateExport { index },
+                )));
+            }
+
+            index += 1;
+        }
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for ExportSec<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.exports
+    }
+}
+
+impl core::fmt::Debug for ExportSec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(export) => list.entry(&export),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
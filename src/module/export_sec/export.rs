@@ -0,0 +1,39 @@
+use crate::{
+    error::{self, AddCause as _},
+    module::ExportDesc,
+};
+
+/// Represents a [WebAssembly **`export`**].
+///
+/// [WebAssembly **`export`**]: https://webassembly.github.io/spec/core/binary/modules.html#export-section
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct Export<'a> {
+    /// The name of the export.
+    pub name: &'a str,
+    /// The description for the export.
+    pub desc: ExportDesc,
+}
+
+impl<'a> Export<'a> {
+    #[allow(missing_docs)]
+    pub fn parse<E: error::ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let (input, name) = crate::values::name(input).add_cause(error::ErrorCause::ExportName)?;
+
+        let (input, desc) = ExportDesc::parse(input)?;
+
+        Ok((input, Self { name, desc }))
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`Export::parse()`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct ExportParser;
+
+impl<'a, E: error::ErrorSource<'a>> nom::Parser<&'a [u8], Export<'a>, E> for ExportParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, Export<'a>, E> {
+        Export::parse(input)
+    }
+}
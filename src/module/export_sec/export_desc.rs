@@ -0,0 +1,67 @@
+use crate::{
+    error::{self, AddCause as _, ErrorCause},
+    index::Index as _,
+    module::{FuncIdx, GlobalIdx, MemIdx, TableIdx, TagIdx},
+};
+
+/// An [**`exportdesc`**] describes what kind of entity is specified by an [`Export`].
+///
+/// [**`exportdesc`**]: https://webassembly.github.io/spec/core/binary/modules.html#export-section
+/// [`Export`]: crate::module::Export
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ExportDesc {
+    /// An exported function.
+    Func(FuncIdx),
+    /// An exported table.
+    Table(TableIdx),
+    /// An exported memory.
+    Mem(MemIdx),
+    /// An exported global.
+    Global(GlobalIdx),
+    /// An exported tag, introduced as part of the [exception handling proposal].
+    ///
+    /// [exception handling proposal]: https://github.com/WebAssembly/exception-handling/tree/main
+    Tag(TagIdx),
+}
+
+impl ExportDesc {
+    #[allow(missing_docs)]
+    pub fn parse<'a, E: error::ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let start = input;
+        let (input, tag) = if let Some((first, remaining)) = input.split_first() {
+            (remaining, *first)
+        } else {
+            return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                error::ErrorKind::OneOf,
+                ErrorCause::InvalidTag(error::InvalidTag::ExportDesc(None)),
+            )));
+        };
+
+        let bad_desc = move || ErrorCause::ExportDesc { kind: tag };
+
+        match tag {
+            0 => FuncIdx::parse(input)
+                .add_cause_with(bad_desc)
+                .map(|(input, index)| (input, Self::Func(index))),
+            1 => TableIdx::parse(input)
+                .add_cause_with(bad_desc)
+                .map(|(input, index)| (input, Self::Table(index))),
+            2 => MemIdx::parse(input)
+                .add_cause_with(bad_desc)
+                .map(|(input, index)| (input, Self::Mem(index))),
+            3 => GlobalIdx::parse(input)
+                .add_cause_with(bad_desc)
+                .map(|(input, index)| (input, Self::Global(index))),
+            4 => TagIdx::parse(input)
+                .add_cause_with(bad_desc)
+                .map(|(input, index)| (input, Self::Tag(index))),
+            _ => Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                &start[..1],
+                error::ErrorKind::OneOf,
+                ErrorCause::InvalidTag(error::InvalidTag::ExportDesc(Some(tag))),
+            ))),
+        }
+    }
+}
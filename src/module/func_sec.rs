@@ -0,0 +1,66 @@
+use crate::{
+    error::{self, ErrorSource},
+    index::IndexVectorParser,
+    module::TypeIdx,
+};
+use nom::ToUsize;
+
+/// Represents the [*function section*], which specifies the type of each function defined in the
+/// module.
+///
+/// This corresponds to the [**type**] field of each function in the [**funcs** component] of a
+/// WebAssembly module. The locals and bodies of functions are stored separately in the
+/// [*code section*].
+///
+/// [*function section*]: https://webassembly.github.io/spec/core/binary/modules.html#function-section
+/// [**type**]: https://webassembly.github.io/spec/core/syntax/modules.html#functions
+/// [**funcs** component]: https://webassembly.github.io/spec/core/syntax/modules.html#syntax-module
+/// [*code section*]: https://webassembly.github.io/spec/core/binary/modules.html#code-section
+#[derive(Clone, Copy, Default)]
+#[must_use]
+pub struct FuncSec<'a> {
+    count: u32,
+    types: &'a [u8],
+}
+
+impl<'a> FuncSec<'a> {
+    /// Parses a *function section* from a section's contents.
+    pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<Self, E> {
+        let (types, count) = crate::values::vector_length(contents)?;
+        Ok(Self { count, types })
+    }
+
+    /// The expected number of functions defined in the module.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over the [`TypeIdx`] of each function defined in the module.
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`TypeIdx`].
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(&self) -> IndexVectorParser<'a, TypeIdx, E> {
+        IndexVectorParser::new(self.count, self.types, Default::default()).expect_eof()
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for FuncSec<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.types
+    }
+}
+
+impl core::fmt::Debug for FuncSec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(index) => list.entry(&index),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    error::{self, AddCause as _, ErrorCause, ErrorSource},
+    features::WasmFeatures,
+    isa,
+    types::GlobalType,
+    values::VectorIter,
+};
+use nom::ToUsize;
+
+/// Represents a WebAssembly [**`global`**] defined in the [*global section*].
+///
+/// [**`global`**]: https://webassembly.github.io/spec/core/syntax/modules.html#globals
+/// [*global section*]: https://webassembly.github.io/spec/core/binary/modules.html#global-section
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct Global<'a> {
+    /// The type of the value stored in the global, and whether it is mutable.
+    pub global_type: GlobalType,
+    /// The [constant expression] used to initialize the global.
+    ///
+    /// The raw bytes of the expression can be obtained with [`AsInput::as_input()`], while its
+    /// instructions can be parsed with [`isa::Expr::parse_with()`].
+    ///
+    /// [constant expression]: https://webassembly.github.io/spec/core/valid/instructions.html#constant-expressions
+    /// [`AsInput::as_input()`]: crate::input::AsInput::as_input()
+    pub init: isa::Expr<'a>,
+}
+
+impl<'a> Global<'a> {
+    #[allow(missing_docs)]
+    pub fn parse<E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let (input, global_type) = GlobalType::parse(input)?;
+        let (input, init) = isa::Expr::parse(input).add_cause(ErrorCause::GlobalInit)?;
+        Ok((input, Self { global_type, init }))
+    }
+
+    /// Parses a [`Global`], failing if its type or the instructions of its initializer expression
+    /// require any proposals that are not enabled in the given set of `features`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with an [`ErrorCause::FeatureDisabled`] if the global requires a disabled
+    /// proposal, or any other error that [`Global::parse()`] would return.
+    pub fn parse_with_features<E: ErrorSource<'a>>(
+        input: &'a [u8],
+        features: WasmFeatures,
+    ) -> crate::Parsed<'a, Self, E> {
+        let (remaining, global) = Self::parse(input)?;
+        features.check(input, global.global_type.required_features())?;
+        isa::init_expr_features(crate::input::AsInput::as_input(&global.init), features)
+            .add_cause(ErrorCause::GlobalInit)?;
+        Ok((remaining, global))
+    }
+
+    /// Parses the instructions of the global's initializer expression with the given
+    /// [`ParseInstr`](isa::ParseInstr) implementation.
+    #[inline]
+    pub fn parse_init_with<P, E>(&self, parser: P) -> crate::input::Result<P, E>
+    where
+        P: isa::ParseInstr<'a, E>,
+        E: ErrorSource<'a>,
+    {
+        self.init.parse_with(parser)
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`Global::parse_with_features()`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct GlobalParser {
+    /// The set of proposals that parsed globals are allowed to use.
+    pub features: WasmFeatures,
+}
+
+impl GlobalParser {
+    /// Creates a parser that only accepts globals allowed by the given `features`.
+    #[inline]
+    pub const fn with_features(features: WasmFeatures) -> Self {
+        Self { features }
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], Global<'a>, E> for GlobalParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, Global<'a>, E> {
+        Global::parse_with_features(input, self.features)
+    }
+}
+
+/// Represents the [*global section*].
+///
+/// This corresponds to the [**globals** component] of a WebAssembly module.
+///
+/// [*global section*]: https://webassembly.github.io/spec/core/binary/modules.html#global-section
+/// [**globals** component]: https://webassembly.github.io/spec/core/syntax/modules.html#globals
+#[derive(Clone, Copy)]
+#[must_use]
+pub struct GlobalSec<'a> {
+    count: u32,
+    globals: &'a [u8],
+    features: WasmFeatures,
+}
+
+impl<'a> GlobalSec<'a> {
+    /// Parses a *global section* from a section's contents.
+    #[inline]
+    pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<Self, E> {
+        Self::parse_with_features(contents, WasmFeatures::ALL)
+    }
+
+    /// Parses a *global section* from a section's contents, with the set of `features` that the
+    /// [`Global`]s within the section are allowed to use.
+    pub fn parse_with_features<E: ErrorSource<'a>>(
+        contents: &'a [u8],
+        features: WasmFeatures,
+    ) -> crate::input::Result<Self, E> {
+        let (globals, count) = crate::values::vector_length(contents)?;
+        Ok(Self {
+            count,
+            globals,
+            features,
+        })
+    }
+
+    /// The expected number of [`Global`]s within the section.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over each [`Global`] within the section.
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`Global`], or
+    /// if a [`Global`] requires a proposal that is not enabled.
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(&self) -> VectorIter<'a, Global<'a>, E, GlobalParser> {
+        let parser = GlobalParser::with_features(self.features);
+        VectorIter::new(self.count, self.globals, parser).expect_eof()
+    }
+}
+
+impl Default for GlobalSec<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            count: 0,
+            globals: &[],
+            features: WasmFeatures::ALL,
+        }
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for GlobalSec<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.globals
+    }
+}
+
+impl core::fmt::Debug for GlobalSec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(global) => list.entry(&global),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
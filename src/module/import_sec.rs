@@ -1,57 +1,125 @@
-use crate::error;
+use crate::{error, features::WasmFeatures, values::VectorIter};
 use nom::ToUsize;
 
 mod import;
 mod import_desc;
+mod import_group;
+mod imports;
 
-pub use import::Import;
+pub use import::{Import, ImportParser};
 pub use import_desc::ImportDesc;
+pub use import_group::{GroupedImportParser, ImportEntry, ImportEntryParser, ImportGroup};
+pub use imports::Imports;
 
 /// Represents the [*import section*].
 ///
 /// This corresponds to the [**imports** component] of a WebAssembly module.
 ///
+/// Imports that share a module name may be encoded as an [`ImportGroup`], as introduced in the
+/// [compact import section proposal].
+///
 /// [*import section*]: https://webassembly.github.io/spec/core/binary/modules.html#import-section
 /// [**imports** component]: https://webassembly.github.io/spec/core/syntax/modules.html#imports
-#[derive(Clone, Copy, Default)]
+/// [compact import section proposal]: https://github.com/WebAssembly/compact-import-section
+#[derive(Clone, Copy)]
 #[must_use]
 pub struct ImportSec<'a> {
     count: u32,
     imports: &'a [u8],
+    features: WasmFeatures,
 }
 
 impl<'a> ImportSec<'a> {
     /// Parses an [`Import`] section from a section's contents.
+    #[inline]
     pub fn parse<E>(contents: &'a [u8]) -> crate::input::Result<Self, E>
+    where
+        E: error::ErrorSource<'a>,
+    {
+        Self::parse_with_features(contents, WasmFeatures::ALL)
+    }
+
+    /// Parses an [`Import`] section from a section's contents, with the set of `features` that
+    /// the types of the imported entities are allowed to use.
+    pub fn parse_with_features<E>(
+        contents: &'a [u8],
+        features: WasmFeatures,
+    ) -> crate::input::Result<Self, E>
     where
         E: error::ErrorSource<'a>,
     {
         let (imports, count) = crate::values::vector_length(contents)?;
-        Ok(Self { count, imports })
+        Ok(Self {
+            count,
+            imports,
+            features,
+        })
     }
 
-    /// The expected number of [`Import`]s within the section.
+    /// The expected number of [`ImportEntry`]s within the section.
+    ///
+    /// Since each [`ImportGroup`] can contain any number of [`Import`]s, this is not necessarily
+    /// the number of [`Import`]s within the section.
     #[inline]
     pub fn count(&self) -> usize {
         self.count.to_usize()
     }
 
     /// Parses each [`Import`] within the section, passing them to the given closure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an [`Import`] could not be parsed, or if there were bytes remaining
+    /// after the last [`Import`] was parsed.
     pub fn parse_contents<E, F>(&self, mut f: F) -> crate::Parsed<'a, (), E>
     where
         E: error::ErrorSource<'a>,
         F: FnMut(Import<'a>),
     {
-        crate::values::sequence(self.imports, self.count(), |input| {
-            let (input, import) = Import::parse(input)?;
-            f(import);
-            Ok((input, ()))
-        })
+        for result in self.iter_contents() {
+            f(result?);
+        }
+
+        Ok((&self.imports[self.imports.len()..], ()))
+    }
+
+    /// Returns an [`Iterator`] over each [`Import`] within the section, including those within
+    /// each [`ImportGroup`].
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`Import`], or
+    /// if an [`Import`] requires a proposal that is not enabled.
+    #[inline]
+    pub fn iter_contents<E>(&self) -> Imports<'a, E>
+    where
+        E: error::ErrorSource<'a>,
+    {
+        Imports::new(self.iter_entries())
+    }
 
-        // TODO: Check for EOF
+    /// Returns an [`Iterator`] over each [`ImportEntry`] within the section, without expanding
+    /// the [`ImportGroup`]s.
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`ImportEntry`],
+    /// or if an [`ImportEntry`] requires a proposal that is not enabled.
+    #[inline]
+    pub fn iter_entries<E>(&self) -> VectorIter<'a, ImportEntry<'a>, E, ImportEntryParser>
+    where
+        E: error::ErrorSource<'a>,
+    {
+        let parser = ImportEntryParser::with_features(self.features);
+        VectorIter::new(self.count, self.imports, parser).expect_eof()
     }
+}
 
-    //pub fn iter_contents(&self) -> IterImportSec // or crate::values::IterVector<'a, E, ImportParser>
+impl Default for ImportSec<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            count: 0,
+            imports: &[],
+            features: WasmFeatures::ALL,
+        }
+    }
 }
 
 impl<'a> crate::input::AsInput<'a> for ImportSec<'a> {
@@ -63,16 +131,13 @@ impl<'a> crate::input::AsInput<'a> for ImportSec<'a> {
 
 impl core::fmt::Debug for ImportSec<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        // TODO: Use iter_contents?
         let mut list = f.debug_list();
-        let result = self.parse_contents::<error::Error, _>(|import| {
-            list.entry(&import);
-        });
-
-        if let Err(err) = result {
-            list.entry(&err);
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(import) => list.entry(&import),
+                Err(err) => list.entry(&err),
+            };
         }
-
         list.finish()
     }
 }
@@ -1,6 +1,10 @@
 use crate::{
     error::{self, AddCause as _},
-    module::ImportDesc,
+    features::WasmFeatures,
+    module::{
+        import_sec::import_group::{GROUP_TAG, GROUP_WITH_DESC_TAG},
+        ImportDesc,
+    },
 };
 
 /// Represents a [WebAssembly **`import`**].
@@ -21,11 +25,29 @@ pub struct Import<'a> {
 }
 
 impl<'a> Import<'a> {
-    #[allow(missing_docs)]
+    /// Parses a single [`Import`].
+    ///
+    /// Compact import groups are not supported here, since they contain multiple imports. Use
+    /// [`ImportEntry::parse()`](crate::module::ImportEntry::parse) to parse an import that may be
+    /// part of an [`ImportGroup`](crate::module::ImportGroup).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the import could not be parsed, or with an
+    /// [`ErrorCause::ImportGroup`](error::ErrorCause::ImportGroup) if the `input` contains an
+    /// [`ImportGroup`](crate::module::ImportGroup) instead.
     pub fn parse<E: error::ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
         let (input, module) = crate::values::name(input)
             .add_cause(error::ErrorCause::Import(error::ImportComponent::Module))?;
 
+        if let [0, GROUP_TAG | GROUP_WITH_DESC_TAG, ..] = input {
+            return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                error::ErrorKind::Verify,
+                error::ErrorCause::ImportGroup,
+            )));
+        }
+
         let (input, name) = crate::values::name(input)
             .add_cause(error::ErrorCause::Import(error::ImportComponent::Name))?;
 
@@ -33,4 +55,45 @@ impl<'a> Import<'a> {
 
         Ok((input, Self { module, name, desc }))
     }
+
+    /// Parses a single [`Import`], failing if the type of the imported entity requires any
+    /// proposals that are not enabled in the given set of `features`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with an [`ErrorCause::FeatureDisabled`] if the import requires a disabled
+    /// proposal, or any other error that [`Import::parse()`] would return.
+    ///
+    /// [`ErrorCause::FeatureDisabled`]: error::ErrorCause::FeatureDisabled
+    pub fn parse_with_features<E: error::ErrorSource<'a>>(
+        input: &'a [u8],
+        features: WasmFeatures,
+    ) -> crate::Parsed<'a, Self, E> {
+        let (remaining, import) = Self::parse(input)?;
+        features.check(input, import.desc.required_features())?;
+        Ok((remaining, import))
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`Import::parse_with_features()`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct ImportParser {
+    /// The set of proposals that parsed imports are allowed to use.
+    pub features: WasmFeatures,
+}
+
+impl ImportParser {
+    /// Creates a parser that only accepts imports allowed by the given `features`.
+    #[inline]
+    pub const fn with_features(features: WasmFeatures) -> Self {
+        Self { features }
+    }
+}
+
+impl<'a, E: error::ErrorSource<'a>> nom::Parser<&'a [u8], Import<'a>, E> for ImportParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, Import<'a>, E> {
+        Import::parse_with_features(input, self.features)
+    }
 }
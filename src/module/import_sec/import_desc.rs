@@ -1,5 +1,6 @@
 use crate::{
     error::{self, AddCause as _, ErrorCause},
+    features::WasmFeatures,
     index::Index as _,
     types,
 };
@@ -63,4 +64,15 @@ impl ImportDesc {
             ))),
         }
     }
+
+    /// Gets the proposals that are required to use the type of the imported entity.
+    pub fn required_features(&self) -> WasmFeatures {
+        match self {
+            Self::Function(_) => WasmFeatures::MVP,
+            Self::Table(table_type) => table_type.required_features(),
+            Self::Memory(mem_type) => mem_type.required_features(),
+            Self::Global(global_type) => global_type.required_features(),
+            Self::Tag(tag_type) => tag_type.required_features(),
+        }
+    }
 }
@@ -0,0 +1,194 @@
+use crate::{
+    error::{self, AddCause as _},
+    features::WasmFeatures,
+    module::{Import, ImportDesc},
+    values::VectorIter,
+};
+use nom::ToUsize;
+
+pub(super) const GROUP_TAG: u8 = 0x7F;
+pub(super) const GROUP_WITH_DESC_TAG: u8 = 0x7E;
+
+/// Represents a group of [`Import`]s that share the same module name, introduced as part of the
+/// [compact import section proposal].
+///
+/// [compact import section proposal]: https://github.com/WebAssembly/compact-import-section
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct ImportGroup<'a> {
+    /// The name of the module that all of the imports in the group originate from.
+    pub module: &'a str,
+    /// The description shared by all of the imports in the group, or `None` if each import
+    /// specifies its own.
+    pub desc: Option<ImportDesc>,
+    count: u32,
+    imports: &'a [u8],
+    features: WasmFeatures,
+}
+
+impl<'a> ImportGroup<'a> {
+    /// The number of [`Import`]s within the group.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over each [`Import`] within the group.
+    #[inline]
+    pub fn iter<E: error::ErrorSource<'a>>(
+        &self,
+    ) -> VectorIter<'a, Import<'a>, E, GroupedImportParser<'a>> {
+        let parser = GroupedImportParser {
+            module: self.module,
+            desc: self.desc,
+            features: self.features,
+        };
+
+        VectorIter::new(self.count, self.imports, parser).expect_eof()
+    }
+}
+
+impl core::fmt::Debug for ImportGroup<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter::<error::Error>() {
+            match result {
+                Ok(import) => list.entry(&import),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for the [`Import`]s within an [`ImportGroup`].
+#[derive(Clone, Copy, Debug)]
+pub struct GroupedImportParser<'a> {
+    module: &'a str,
+    desc: Option<ImportDesc>,
+    features: WasmFeatures,
+}
+
+impl<'a, E: error::ErrorSource<'a>> nom::Parser<&'a [u8], Import<'a>, E>
+    for GroupedImportParser<'a>
+{
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, Import<'a>, E> {
+        let (input, name) = crate::values::name(input)
+            .add_cause(error::ErrorCause::Import(error::ImportComponent::Name))?;
+
+        let (input, desc) = if let Some(desc) = self.desc {
+            (input, desc)
+        } else {
+            let (remaining, desc) = ImportDesc::parse(input)?;
+            self.features.check(input, desc.required_features())?;
+            (remaining, desc)
+        };
+
+        Ok((
+            input,
+            Import {
+                module: self.module,
+                name,
+                desc,
+            },
+        ))
+    }
+}
+
+/// An entry in the [*import section*], which is either a single [`Import`] or an
+/// [`ImportGroup`].
+///
+/// [*import section*]: crate::module::ImportSec
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum ImportEntry<'a> {
+    #[allow(missing_docs)]
+    Single(Import<'a>),
+    #[allow(missing_docs)]
+    Group(ImportGroup<'a>),
+}
+
+impl<'a> ImportEntry<'a> {
+    /// Parses an [`Import`], or an [`ImportGroup`] if the module name is followed by an empty
+    /// name and a `0x7F` or `0x7E` byte.
+    #[inline]
+    pub fn parse<E: error::ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        Self::parse_with_features(input, WasmFeatures::ALL)
+    }
+
+    /// Parses an [`Import`] or an [`ImportGroup`], failing if the type of any imported entity
+    /// requires proposals that are not enabled in the given set of `features`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with an [`ErrorCause::FeatureDisabled`] if an import requires a disabled
+    /// proposal, or any other error that [`ImportEntry::parse()`] would return.
+    ///
+    /// [`ErrorCause::FeatureDisabled`]: error::ErrorCause::FeatureDisabled
+    pub fn parse_with_features<E: error::ErrorSource<'a>>(
+        input: &'a [u8],
+        features: WasmFeatures,
+    ) -> crate::Parsed<'a, Self, E> {
+        let (after_module, module) = crate::values::name(input)
+            .add_cause(error::ErrorCause::Import(error::ImportComponent::Module))?;
+
+        let (imports, desc) = match after_module {
+            [0, GROUP_TAG, imports @ ..] => (imports, None),
+            [0, GROUP_WITH_DESC_TAG, desc_start @ ..] => {
+                let (imports, desc) = ImportDesc::parse(desc_start)?;
+                features.check(desc_start, desc.required_features())?;
+                (imports, Some(desc))
+            }
+            _ => {
+                return Import::parse_with_features(input, features)
+                    .map(|(input, import)| (input, Self::Single(import)))
+            }
+        };
+
+        let mut parser = GroupedImportParser {
+            module,
+            desc,
+            features,
+        };
+
+        let (imports, count) = crate::values::vector_length(imports)?;
+        let (input, ()) = crate::values::sequence(imports, count, |input| {
+            nom::Parser::parse(&mut parser, input).map(|(input, _)| (input, ()))
+        })?;
+
+        Ok((
+            input,
+            Self::Group(ImportGroup {
+                module,
+                desc,
+                count,
+                imports: &imports[..imports.len() - input.len()],
+                features,
+            }),
+        ))
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`ImportEntry::parse_with_features()`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct ImportEntryParser {
+    /// The set of proposals that parsed imports are allowed to use.
+    pub features: WasmFeatures,
+}
+
+impl ImportEntryParser {
+    /// Creates a parser that only accepts imports allowed by the given `features`.
+    #[inline]
+    pub const fn with_features(features: WasmFeatures) -> Self {
+        Self { features }
+    }
+}
+
+impl<'a, E: error::ErrorSource<'a>> nom::Parser<&'a [u8], ImportEntry<'a>, E>
+    for ImportEntryParser
+{
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, ImportEntry<'a>, E> {
+        ImportEntry::parse_with_features(input, self.features)
+    }
+}
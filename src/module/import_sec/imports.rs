@@ -0,0 +1,69 @@
+use crate::{
+    error::ErrorSource,
+    module::{GroupedImportParser, Import, ImportEntry, ImportEntryParser},
+    values::VectorIter,
+};
+
+/// Provides an [`Iterator`] over each [`Import`] within an [*import section*], expanding any
+/// [`ImportGroup`]s into the [`Import`]s that they contain.
+///
+/// [*import section*]: crate::module::ImportSec
+/// [`ImportGroup`]: crate::module::ImportGroup
+#[derive(Clone)]
+#[must_use]
+pub struct Imports<'a, E: ErrorSource<'a> = crate::error::Error<'a>> {
+    entries: VectorIter<'a, ImportEntry<'a>, E, ImportEntryParser>,
+    group: Option<VectorIter<'a, Import<'a>, E, GroupedImportParser<'a>>>,
+}
+
+impl<'a, E: ErrorSource<'a>> Imports<'a, E> {
+    pub(super) fn new(entries: VectorIter<'a, ImportEntry<'a>, E, ImportEntryParser>) -> Self {
+        Self {
+            entries,
+            group: None,
+        }
+    }
+
+    /// Gets the remaining input after all of the [`Import`]s have been parsed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the remaining [`Import`]s could not be parsed.
+    pub fn finish(mut self) -> crate::Parsed<'a, (), E> {
+        for result in &mut self {
+            let _ = result?;
+        }
+
+        Ok((crate::input::AsInput::as_input(&self.entries), ()))
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> Iterator for Imports<'a, E> {
+    type Item = crate::input::Result<Import<'a>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(group) = &mut self.group {
+                if let Some(result) = group.next() {
+                    return Some(result);
+                }
+
+                self.group = None;
+            }
+
+            match self.entries.next()? {
+                Ok(ImportEntry::Single(import)) => return Some(Ok(import)),
+                Ok(ImportEntry::Group(group)) => self.group = Some(group.iter()),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> core::iter::FusedIterator for Imports<'a, E> {}
+
+impl<'a, E: ErrorSource<'a>> core::fmt::Debug for Imports<'a, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Imports").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    error::{self, ErrorSource},
+    features::WasmFeatures,
+    types::{MemType, MemTypeParser},
+    values::VectorIter,
+};
+use nom::ToUsize;
+
+/// Represents the [*memory section*].
+///
+/// This corresponds to the [**mems** component] of a WebAssembly module.
+///
+/// Note that defining more than one memory requires the [multi-memory proposal].
+///
+/// [*memory section*]: https://webassembly.github.io/spec/core/binary/modules.html#memory-section
+/// [**mems** component]: https://webassembly.github.io/spec/core/syntax/modules.html#memories
+/// [multi-memory proposal]: https://github.com/WebAssembly/multi-memory
+#[derive(Clone, Copy)]
+#[must_use]
+pub struct MemSec<'a> {
+    count: u32,
+    mems: &'a [u8],
+    features: WasmFeatures,
+}
+
+impl<'a> MemSec<'a> {
+    /// Parses a *memory section* from a section's contents.
+    #[inline]
+    pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<Self, E> {
+        Self::parse_with_features(contents, WasmFeatures::ALL)
+    }
+
+    /// Parses a *memory section* from a section's contents, with the set of `features` that the
+    /// [`MemType`]s within the section are allowed to use.
+    pub fn parse_with_features<E: ErrorSource<'a>>(
+        contents: &'a [u8],
+        features: WasmFeatures,
+    ) -> crate::input::Result<Self, E> {
+        let (mems, count) = crate::values::vector_length(contents)?;
+        Ok(Self {
+            count,
+            mems,
+            features,
+        })
+    }
+
+    /// The expected number of memories defined in the module.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over the [`MemType`] of each memory defined in the module.
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`MemType`], or
+    /// if a [`MemType`] requires a proposal that is not enabled.
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(&self) -> VectorIter<'a, MemType, E, MemTypeParser> {
+        let parser = MemTypeParser::with_features(self.features);
+        VectorIter::new(self.count, self.mems, parser).expect_eof()
+    }
+}
+
+impl Default for MemSec<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            count: 0,
+            mems: &[],
+            features: WasmFeatures::ALL,
+        }
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for MemSec<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.mems
+    }
+}
+
+impl core::fmt::Debug for MemSec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(mem_type) => list.entry(&mem_type),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
@@ -1,9 +1,16 @@
-use crate::{module, section::Section};
+use crate::{features::WasmFeatures, module, section::Section};
 
 macro_rules! module_sections {
+    (@parse $contents:expr, $features:ident, $parse:path) => {{
+        let _ = $features;
+        $parse($contents)
+    }};
+    (@parse $contents:expr, $features:ident, $parse:path, $parse_with_features:path) => {
+        $parse_with_features($contents, $features)
+    };
     ($(
         $(#[$meta:meta])*
-        [$id:literal]$name:ident($component:ty) $(impl $from:ident)? => $parse:path,
+        [$id:literal]$name:ident($component:ty) $(impl $from:ident)? => $parse:path $(| $parse_with_features:path)?,
     )+) => {
         /// Represents a well-known WebAssembly module [*section*] or a [`CustomSection`].
         ///
@@ -46,15 +53,40 @@ macro_rules! module_sections {
             /// - Returns `Err(_)` if the [`Section`] is not a known module section or a custom section.
             /// - Returns `Ok(Err(_))` if the section was a known module section or custom
             ///   section, but it could not be parsed.
+            #[inline]
             pub fn interpret_section<'b, E>(
                 section: &'b Section<'a>
             ) -> Result<crate::input::Result<Self, E>, &'b Section<'a>>
+            where
+                E: crate::error::ErrorSource<'a>,
+            {
+                Self::interpret_section_with_features(section, WasmFeatures::ALL)
+            }
+
+            /// Attempts to interpret the contents of a WebAssembly module [`Section`], with the
+            /// set of `features` that the section is allowed to use.
+            ///
+            /// The contents of sections such as the *type section* are checked when they are
+            /// iterated over or parsed. See the documentation for [`ModuleSection::interpret_section()`] for more
+            /// information.
+            ///
+            /// # Errors
+            ///
+            /// Returns `Ok(Err(_))` with an [`ErrorCause::FeatureDisabled`] if the section
+            /// requires a disabled proposal, such as a *tag section* without the
+            /// [exception handling proposal](WasmFeatures::EXCEPTION_HANDLING).
+            ///
+            /// [`ErrorCause::FeatureDisabled`]: crate::error::ErrorCause::FeatureDisabled
+            pub fn interpret_section_with_features<'b, E>(
+                section: &'b Section<'a>,
+                features: WasmFeatures,
+            ) -> Result<crate::input::Result<Self, E>, &'b Section<'a>>
             where
                 E: crate::error::ErrorSource<'a>,
             {
                 match section.id {
                     $(
-                        $id => Ok($parse(section.contents).map(Self::$name)),
+                        $id => Ok(module_sections!(@parse section.contents, features, $parse $(, $parse_with_features)?).map(Self::$name)),
                     )+
                     _ => Err(section),
                 }
@@ -83,9 +115,55 @@ module_sections! {
     /// The [*type section*].
     ///
     /// [*type section*]: https://webassembly.github.io/spec/core/binary/modules.html#type-section
-    [1]Type(module::TypeSec<'a>) impl From => module::TypeSec::parse,
+    [1]Type(module::TypeSec<'a>) impl From => module::TypeSec::parse | module::TypeSec::parse_with_features,
     /// The [*import section*].
     ///
     /// [*import section*]: https://webassembly.github.io/spec/core/binary/modules.html#import-section
-    [2]Import(module::ImportSec<'a>) impl From => module::ImportSec::parse,
+    [2]Import(module::ImportSec<'a>) impl From => module::ImportSec::parse | module::ImportSec::parse_with_features,
+    /// The [*function section*].
+    ///
+    /// [*function section*]: https://webassembly.github.io/spec/core/binary/modules.html#function-section
+    [3]Func(module::FuncSec<'a>) impl From => module::FuncSec::parse,
+    /// The [*table section*].
+    ///
+    /// [*table section*]: https://webassembly.github.io/spec/core/binary/modules.html#table-section
+    [4]Table(module::TableSec<'a>) impl From => module::TableSec::parse | module::TableSec::parse_with_features,
+    /// The [*memory section*].
+    ///
+    /// [*memory section*]: https://webassembly.github.io/spec/core/binary/modules.html#memory-section
+    [5]Mem(module::MemSec<'a>) impl From => module::MemSec::parse | module::MemSec::parse_with_features,
+    /// The [*global section*].
+    ///
+    /// [*global section*]: https://webassembly.github.io/spec/core/binary/modules.html#global-section
+    [6]Global(module::GlobalSec<'a>) impl From => module::GlobalSec::parse | module::GlobalSec::parse_with_features,
+    /// The [*export section*].
+    ///
+    /// [*export section*]: https://webassembly.github.io/spec/core/binary/modules.html#export-section
+    [7]Export(module::ExportSec<'a>) impl From => module::ExportSec::parse,
+    /// The [*start section*], which specifies the module's start function.
+    ///
+    /// [*start section*]: https://webassembly.github.io/spec/core/binary/modules.html#start-section
+    [8]Start(module::FuncIdx) => module::start_sec,
+    /// The [*element section*].
+    ///
+    /// [*element section*]: https://webassembly.github.io/spec/core/binary/modules.html#element-section
+    [9]Elem(module::ElemSec<'a>) impl From => module::ElemSec::parse,
+    /// The [*code section*].
+    ///
+    /// [*code section*]: https://webassembly.github.io/spec/core/binary/modules.html#code-section
+    [10]Code(module::CodeSec<'a>) impl From => module::CodeSec::parse,
+    /// The [*data section*].
+    ///
+    /// [*data section*]: https://webassembly.github.io/spec/core/binary/modules.html#data-section
+    [11]Data(module::DataSec<'a>) impl From => module::DataSec::parse,
+    /// The [*data count section*], which specifies the number of data segments in the
+    /// *data section*.
+    ///
+    /// [*data count section*]: https://webassembly.github.io/spec/core/binary/modules.html#data-count-section
+    [12]DataCount(u32) => module::data_count_sec,
+    /// The [*tag section*], introduced as part of the [exception handling proposal].
+    ///
+    /// [*tag section*]: https://webassembly.github.io/exception-handling/core/binary/modules.html#tag-section
+    /// [exception handling proposal]: https://github.com/WebAssembly/exception-handling
+    [13]Tag(module::TagSec<'a>) impl From => module::TagSec::parse | module::TagSec::parse_with_features,
 }
@@ -1,5 +1,6 @@
 use crate::{
     error::{self, ErrorSource},
+    features::WasmFeatures,
     input::Result,
     module::{ModuleSection, ModuleSectionId},
     section::Section,
@@ -31,6 +32,17 @@ impl ModuleSectionOrder {
             ModuleSectionId::Custom => return None,
             ModuleSectionId::Type => Self::Type,
             ModuleSectionId::Import => Self::Import,
+            ModuleSectionId::Func => Self::Func,
+            ModuleSectionId::Table => Self::Table,
+            ModuleSectionId::Mem => Self::Mem,
+            ModuleSectionId::Tag => Self::Tag,
+            ModuleSectionId::Global => Self::Global,
+            ModuleSectionId::Export => Self::Export,
+            ModuleSectionId::Start => Self::Start,
+            ModuleSectionId::Elem => Self::Elem,
+            ModuleSectionId::Code => Self::Code,
+            ModuleSectionId::Data => Self::Data,
+            ModuleSectionId::DataCount => Self::DataCount,
         })
     }
 }
@@ -68,8 +80,24 @@ impl core::fmt::Display for ModuleSectionOrder {
 /// correct order.
 ///
 /// [`preamble`]: crate::module::preamble
+#[inline]
 pub fn module_section_sequence_with_unknown<'a, E, F, G>(
     input: &'a [u8],
+    f: F,
+    g: G,
+) -> Result<(), E>
+where
+    E: ErrorSource<'a>,
+    F: FnMut(ModuleSection<'a>, Option<ModuleSectionOrder>) -> Result<(), E>,
+    G: FnMut(&'a [u8], Section<'a>, Option<ModuleSectionOrder>) -> Result<(), E>,
+{
+    sequence_with_features(input, WasmFeatures::ALL, f, g)
+}
+
+/// Parses the sequence of [`ModuleSection`]s, interpreting each with the given set of `features`.
+pub(crate) fn sequence_with_features<'a, E, F, G>(
+    input: &'a [u8],
+    features: WasmFeatures,
     mut f: F,
     mut g: G,
 ) -> Result<(), E>
@@ -79,9 +107,8 @@ where
     G: FnMut(&'a [u8], Section<'a>, Option<ModuleSectionOrder>) -> Result<(), E>,
 {
     let mut order = crate::ordering::Ordering::new();
-    crate::section::sequence(
-        input,
-        |input, section| match ModuleSection::interpret_section(&section) {
+    crate::section::sequence(input, |input, section| {
+        match ModuleSection::interpret_section_with_features(&section, features) {
             Ok(result) => {
                 let known = result?;
                 if let Some(next) = ModuleSectionOrder::from_section_id(known.id()) {
@@ -96,11 +123,11 @@ where
                 f(known, *order.previous())
             }
             Err(_) => g(input, section, *order.previous()),
-        },
-    )
+        }
+    })
 }
 
-fn no_unknown_section<'a, E: ErrorSource<'a>>(
+pub(crate) fn no_unknown_section<'a, E: ErrorSource<'a>>(
     input: &'a [u8],
     section: Section<'a>,
     _: Option<ModuleSectionOrder>,
@@ -34,7 +34,7 @@ fn parse_magic<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, (), E> {
 ///
 /// To handle different version values, use [`parse_any()`].
 pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, (), E> {
-    parse_magic(input)?;
+    let (input, ()) = parse_magic(input)?;
     nom::bytes::complete::tag(RECOGNIZED_VERSION)(input)
         .map(|(remaining, _)| (remaining, ()))
         .add_cause_with(|| {
@@ -50,7 +50,7 @@ pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, (), E> {
 ///
 /// If you don't want to handle special version values, use [`parse()`] instead.
 pub fn parse_any<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, [u8; 4], E> {
-    parse_magic(input)?;
+    let (input, ()) = parse_magic(input)?;
     nom::bytes::complete::take(4usize)(input)
         .map(|(remaining, version)| (remaining, version.try_into().unwrap()))
         .add_cause(ErrorCause::PreambleVersion(None))
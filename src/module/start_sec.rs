@@ -0,0 +1,27 @@
+use crate::{
+    error::{AddCause as _, ErrorCause, ErrorKind, ErrorSource},
+    index::Index as _,
+    module::FuncIdx,
+};
+
+/// Parses the contents of the [*start section*], which contains the [`FuncIdx`] of the function
+/// that is automatically invoked when the module is instantiated.
+///
+/// # Errors
+///
+/// Returns an error if the index could not be parsed, or if there are bytes remaining after it.
+///
+/// [*start section*]: https://webassembly.github.io/spec/core/binary/modules.html#start-section
+pub fn start_sec<'a, E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<FuncIdx, E> {
+    let (remaining, start) = FuncIdx::parse(contents).add_cause(ErrorCause::StartSec)?;
+
+    if remaining.is_empty() {
+        Ok(start)
+    } else {
+        Err(nom::Err::Failure(E::from_error_kind_and_cause(
+            remaining,
+            ErrorKind::Eof,
+            ErrorCause::StartSec,
+        )))
+    }
+}
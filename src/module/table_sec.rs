@@ -0,0 +1,206 @@
+use crate::{
+    error::{self, AddCause as _, ErrorCause, ErrorSource},
+    features::WasmFeatures,
+    isa,
+    types::TableType,
+    values::VectorIter,
+};
+use nom::ToUsize;
+
+/// Represents a WebAssembly [**`table`**] defined in the [*table section*].
+///
+/// [**`table`**]: https://webassembly.github.io/spec/core/syntax/modules.html#tables
+/// [*table section*]: https://webassembly.github.io/spec/core/binary/modules.html#table-section
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct Table<'a> {
+    /// The type of the elements stored in the table, and its limits.
+    pub table_type: TableType,
+    /// An optional expression used to initialize the elements of the table.
+    ///
+    /// This requires the [function references proposal].
+    ///
+    /// [function references proposal]: https://github.com/WebAssembly/function-references
+    pub init: Option<isa::Expr<'a>>,
+}
+
+impl<'a> Table<'a> {
+    const INIT_PREFIX: u8 = 0x40;
+
+    #[allow(missing_docs)]
+    pub fn parse<E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let input = match input.split_first() {
+            Some((&Self::INIT_PREFIX, remaining)) => remaining,
+            _ => {
+                return TableType::parse(input).map(|(input, table_type)| {
+                    (
+                        input,
+                        Self {
+                            table_type,
+                            init: None,
+                        },
+                    )
+                })
+            }
+        };
+
+        let input = if let Some((0, remaining)) = input.split_first() {
+            remaining
+        } else {
+            return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                input,
+                error::ErrorKind::Tag,
+                ErrorCause::TableInit,
+            )));
+        };
+
+        let (input, table_type) = TableType::parse(input)?;
+        let (input, init) = isa::Expr::parse(input).add_cause(ErrorCause::TableInit)?;
+        Ok((
+            input,
+            Self {
+                table_type,
+                init: Some(init),
+            },
+        ))
+    }
+
+    /// Parses a [`Table`], failing if its type or initializer expression requires any proposals
+    /// that are not enabled in the given set of `features`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with an [`ErrorCause::FeatureDisabled`] if the table requires a disabled
+    /// proposal, or any other error that [`Table::parse()`] would return.
+    pub fn parse_with_features<E: ErrorSource<'a>>(
+        input: &'a [u8],
+        features: WasmFeatures,
+    ) -> crate::Parsed<'a, Self, E> {
+        let (remaining, table) = Self::parse(input)?;
+        features.check(input, table.required_features())?;
+        if let Some(init) = table.init {
+            isa::init_expr_features(crate::input::AsInput::as_input(&init), features)
+                .add_cause(ErrorCause::TableInit)?;
+        }
+
+        Ok((remaining, table))
+    }
+
+    /// Gets the proposals that are required to use this table's type and initializer expression.
+    ///
+    /// The instructions of the initializer expression are not taken into account.
+    pub fn required_features(&self) -> WasmFeatures {
+        let features = self.table_type.required_features();
+        if self.init.is_some() {
+            features | WasmFeatures::FUNCTION_REFERENCES
+        } else {
+            features
+        }
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`Table::parse_with_features()`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct TableParser {
+    /// The set of proposals that parsed tables are allowed to use.
+    pub features: WasmFeatures,
+}
+
+impl TableParser {
+    /// Creates a parser that only accepts tables allowed by the given `features`.
+    #[inline]
+    pub const fn with_features(features: WasmFeatures) -> Self {
+        Self { features }
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], Table<'a>, E> for TableParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, Table<'a>, E> {
+        Table::parse_with_features(input, self.features)
+    }
+}
+
+/// Represents the [*table section*].
+///
+/// This corresponds to the [**tables** component] of a WebAssembly module.
+///
+/// [*table section*]: https://webassembly.github.io/spec/core/binary/modules.html#table-section
+/// [**tables** component]: https://webassembly.github.io/spec/core/syntax/modules.html#tables
+#[derive(Clone, Copy)]
+#[must_use]
+pub struct TableSec<'a> {
+    count: u32,
+    tables: &'a [u8],
+    features: WasmFeatures,
+}
+
+impl<'a> TableSec<'a> {
+    /// Parses a *table section* from a section's contents.
+    #[inline]
+    pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<Self, E> {
+        Self::parse_with_features(contents, WasmFeatures::ALL)
+    }
+
+    /// Parses a *table section* from a section's contents, with the set of `features` that the
+    /// [`Table`]s within the section are allowed to use.
+    pub fn parse_with_features<E: ErrorSource<'a>>(
+        contents: &'a [u8],
+        features: WasmFeatures,
+    ) -> crate::input::Result<Self, E> {
+        let (tables, count) = crate::values::vector_length(contents)?;
+        Ok(Self {
+            count,
+            tables,
+            features,
+        })
+    }
+
+    /// The expected number of [`Table`]s within the section.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over each [`Table`] within the section.
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`Table`], or if
+    /// a [`Table`] requires a proposal that is not enabled.
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(&self) -> VectorIter<'a, Table<'a>, E, TableParser> {
+        let parser = TableParser::with_features(self.features);
+        VectorIter::new(self.count, self.tables, parser).expect_eof()
+    }
+}
+
+impl Default for TableSec<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            count: 0,
+            tables: &[],
+            features: WasmFeatures::ALL,
+        }
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for TableSec<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.tables
+    }
+}
+
+impl core::fmt::Debug for TableSec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(table) => list.entry(&table),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
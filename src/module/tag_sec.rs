@@ -0,0 +1,99 @@
+use crate::{
+    error::{self, ErrorSource},
+    features::WasmFeatures,
+    types::{TagType, TagTypeParser},
+    values::VectorIter,
+};
+use nom::ToUsize;
+
+/// Represents the [*tag section*], introduced as part of the [exception handling proposal].
+///
+/// This corresponds to the [**tags** component] of a WebAssembly module.
+///
+/// [*tag section*]: https://webassembly.github.io/exception-handling/core/binary/modules.html#tag-section
+/// [exception handling proposal]: https://github.com/WebAssembly/exception-handling
+/// [**tags** component]: https://webassembly.github.io/exception-handling/core/syntax/modules.html#tags
+#[derive(Clone, Copy)]
+#[must_use]
+pub struct TagSec<'a> {
+    count: u32,
+    tags: &'a [u8],
+    features: WasmFeatures,
+}
+
+impl<'a> TagSec<'a> {
+    /// Parses a *tag section* from a section's contents.
+    #[inline]
+    pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> crate::input::Result<Self, E> {
+        Self::parse_with_features(contents, WasmFeatures::ALL)
+    }
+
+    /// Parses a *tag section* from a section's contents, with the set of `features` that the
+    /// [`TagType`]s within the section are allowed to use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with an [`ErrorCause::FeatureDisabled`] if the
+    /// [exception handling proposal](WasmFeatures::EXCEPTION_HANDLING) is not enabled.
+    ///
+    /// [`ErrorCause::FeatureDisabled`]: error::ErrorCause::FeatureDisabled
+    pub fn parse_with_features<E: ErrorSource<'a>>(
+        contents: &'a [u8],
+        features: WasmFeatures,
+    ) -> crate::input::Result<Self, E> {
+        features.check(contents, WasmFeatures::EXCEPTION_HANDLING)?;
+        let (tags, count) = crate::values::vector_length(contents)?;
+        Ok(Self {
+            count,
+            tags,
+            features,
+        })
+    }
+
+    /// The expected number of tags defined in the module.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over the [`TagType`] of each tag defined in the module.
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last [`TagType`], or
+    /// if a [`TagType`] requires a proposal that is not enabled.
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(&self) -> VectorIter<'a, TagType, E, TagTypeParser> {
+        let parser = TagTypeParser::with_features(self.features);
+        VectorIter::new(self.count, self.tags, parser).expect_eof()
+    }
+}
+
+impl Default for TagSec<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            count: 0,
+            tags: &[],
+            features: WasmFeatures::ALL,
+        }
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for TagSec<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.tags
+    }
+}
+
+impl core::fmt::Debug for TagSec<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<error::Error>() {
+            match result {
+                Ok(tag_type) => list.entry(&tag_type),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
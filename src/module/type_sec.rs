@@ -1,8 +1,12 @@
 use crate::{
     error::ErrorSource,
+    features::WasmFeatures,
     input::Result,
     storage::Vector,
-    types::{self, BuildFuncType, FuncType, FuncTypeParser, ParseFuncType},
+    types::{
+        self, BuildFuncType, FuncType, FuncTypeParser, ParseFuncType, ParseRecType, RawRecType,
+    },
+    values::VectorIter,
 };
 use nom::ToUsize as _;
 
@@ -15,33 +19,120 @@ use alloc::vec::Vec;
 ///
 /// [*type section*]: https://webassembly.github.io/spec/core/binary/modules.html#type-section
 /// [**types** component]: https://webassembly.github.io/spec/core/syntax/modules.html#types
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy)]
 #[must_use]
 pub struct TypeSec<'a> {
     count: u32,
     types: &'a [u8],
+    features: WasmFeatures,
 }
 
 impl<'a> TypeSec<'a> {
     /// Parses a *type section* from a section's contents.
+    #[inline]
     pub fn parse<E: ErrorSource<'a>>(contents: &'a [u8]) -> Result<Self, E> {
+        Self::parse_with_features(contents, WasmFeatures::ALL)
+    }
+
+    /// Parses a *type section* from a section's contents, with the set of `features` that the
+    /// [recursive types] within the section are allowed to use.
+    ///
+    /// The types are checked when the contents of the section are parsed.
+    ///
+    /// [recursive types]: types::rec_type_with_features()
+    pub fn parse_with_features<E: ErrorSource<'a>>(
+        contents: &'a [u8],
+        features: WasmFeatures,
+    ) -> Result<Self, E> {
         let (types, count) = crate::values::vector_length(contents)?;
-        Ok(Self { count, types })
+        Ok(Self {
+            count,
+            types,
+            features,
+        })
+    }
+
+    /// Checks that the recursive type at the start of the `input` only requires enabled
+    /// proposals, before it is parsed as a function type.
+    fn check_features<E: ErrorSource<'a>>(&self, input: &'a [u8]) -> Result<(), E> {
+        if !self.features.contains(WasmFeatures::ALL) {
+            let _ = RawRecType::parse_with_features(input, self.features)?;
+        }
+
+        Ok(())
+    }
+
+    /// The expected number of [recursive types] within the section.
+    ///
+    /// Each recursive type can contain multiple sub types, so this may be less than the number of
+    /// types defined by the section. Without the [garbage collection proposal], each recursive
+    /// type is a single function type.
+    ///
+    /// [recursive types]: types::rec_type()
+    /// [garbage collection proposal]: https://github.com/WebAssembly/gc
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count.to_usize()
+    }
+
+    /// Returns an [`Iterator`] over each [recursive type] within the section, without allocating.
+    ///
+    /// Use [`RawRecType::as_func_type()`] to obtain the function types defined in modules that
+    /// do not use the [garbage collection proposal].
+    ///
+    /// The iterator returns an error if there are bytes remaining after the last recursive type,
+    /// or if a recursive type requires a proposal that is not enabled.
+    ///
+    /// [recursive type]: types::rec_type()
+    /// [garbage collection proposal]: https://github.com/WebAssembly/gc
+    #[inline]
+    pub fn iter_contents<E: ErrorSource<'a>>(
+        &self,
+    ) -> VectorIter<'a, RawRecType<'a>, E, types::RawRecTypeParser> {
+        let parser = types::RawRecTypeParser::with_features(self.features);
+        VectorIter::new(self.count, self.types, parser).expect_eof()
     }
 
     /// Returns a struct to parse the contents of the *type section*, using the provided
     /// [`ParseFuncType`] implementation.
+    ///
+    /// Fails if the section contains any types other than function types, such as those
+    /// introduced in the [garbage collection proposal]. Use
+    /// [`TypeSec::parse_rec_types_with()`] to parse those instead.
+    ///
+    /// [garbage collection proposal]: https://github.com/WebAssembly/gc
     pub fn parse_contents_with<P, E>(&self, parser: P) -> Result<P, E>
     where
         P: ParseFuncType,
         E: ErrorSource<'a>,
     {
         let mut f = FuncTypeParser::new(parser);
-        let (input, ()) = crate::values::sequence(self.types, self.count.to_usize(), &mut f)?;
+        let (input, ()) = crate::values::sequence(self.types, self.count.to_usize(), |input| {
+            self.check_features(input)?;
+            nom::Parser::parse(&mut f, input)
+        })?;
         nom::combinator::eof(input)?;
         Ok(f.into_inner())
     }
 
+    /// Parses the contents of the *type section*, which may contain [recursive types] introduced
+    /// in the [garbage collection proposal], using the provided [`ParseRecType`] implementation.
+    ///
+    /// [recursive types]: types::rec_type()
+    /// [garbage collection proposal]: https://github.com/WebAssembly/gc
+    pub fn parse_rec_types_with<P, E>(&self, mut parser: P) -> Result<P, E>
+    where
+        P: ParseRecType,
+        E: ErrorSource<'a>,
+    {
+        let (input, ()) = crate::values::sequence(self.types, self.count.to_usize(), |input| {
+            types::rec_type_with_features(input, self.features, &mut parser)
+                .map(|(input, _)| (input, ()))
+        })?;
+        nom::combinator::eof(input)?;
+        Ok(parser)
+    }
+
     /// Parse the contents of the *type section* with a given [`ParseFuncType`] implementation.
     #[inline]
     pub fn parse_contents<P, E>(&self) -> Result<P, E>
@@ -67,6 +158,7 @@ impl<'a> TypeSec<'a> {
         let count = self.count.to_usize();
         destination.reserve(count);
         let (input, ()) = crate::values::sequence(self.types, count, |input| {
+            self.check_features(input)?;
             let (input, func_type) = FuncType::parse::<E, B>(input, buffer)?;
             destination.push(func_type);
             Ok((input, ()))
@@ -91,6 +183,17 @@ impl<'a> TypeSec<'a> {
     }
 }
 
+impl Default for TypeSec<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            count: 0,
+            types: &[],
+            features: WasmFeatures::ALL,
+        }
+    }
+}
+
 impl<'a> crate::input::AsInput<'a> for TypeSec<'a> {
     #[inline]
     fn as_input(&self) -> &'a [u8] {
@@ -100,10 +203,13 @@ impl<'a> crate::input::AsInput<'a> for TypeSec<'a> {
 
 impl core::fmt::Debug for TypeSec<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        //debug_types(nom::ToUsize::to_usize(&self.count), self.types, f)
-        // TODO: Pretty print the func types instead
-        f.debug_struct("TypeSec")
-            .field("count", &self.count)
-            .finish_non_exhaustive()
+        let mut list = f.debug_list();
+        for result in self.iter_contents::<crate::error::Error>() {
+            match result {
+                Ok(rec_type) => list.entry(&rec_type),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
     }
 }
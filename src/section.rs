@@ -43,7 +43,7 @@ impl<'a> Section<'a> {
             crate::values::leb128_u32(input).add_cause(ErrorCause::SectionLength)?;
 
         if let Some(contents) = input.get(..length.to_usize()) {
-            Ok((&input[..length.to_usize()], Self { id, contents }))
+            Ok((&input[length.to_usize()..], Self { id, contents }))
         } else {
             Err(nom::Err::Failure(E::from_error_kind_and_cause(
                 input,
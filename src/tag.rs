@@ -45,6 +45,7 @@ macro_rules! enumeration_basic {
                 }
 
                 const DEBUG_NAMES: &[*const u8] = &[$(stringify!($case_name).as_ptr()),*];
+                #[allow(clippy::cast_possible_truncation)]
                 const DEBUG_NAME_LENS: &[u8] = &[$(stringify!($case_name).len() as u8),*];
 
                 let key = match self {
@@ -4,17 +4,28 @@
 mod func_type;
 mod global_type;
 mod limits;
+mod rec_type;
 mod result_type;
 mod type_parsers;
 mod val_type;
 
 pub use crate::module::TypeIdx;
-pub use func_type::{func_type, BuildFuncType, FuncType, FuncTypeParser, ParseFuncType};
+pub use func_type::{
+    func_type, BuildFuncType, FuncType, FuncTypeParser, ParseFuncType, RawFuncType,
+    RawFuncTypeParser,
+};
 pub use global_type::{GlobalType, Mutability};
 pub use limits::{IdxType, LimitBounds, Limits, Sharing};
-pub use result_type::{result_type, ParseResultType};
-pub use type_parsers::ValTypeParser;
-pub use val_type::{BlockType, MemType, NumType, RefType, TableType, TagType, ValType, VecType};
+pub use rec_type::{
+    rec_type, rec_type_with_features, FieldType, PackedType, ParseRecType, RawRecType,
+    RawRecTypeParser, StorageType,
+};
+pub use result_type::{result_type, ParseResultType, ResultType};
+pub use type_parsers::{MemTypeParser, TagTypeParser, ValTypeParser};
+pub use val_type::{
+    AbstractHeapType, BlockType, HeapType, MemType, NumType, RefType, TableType, TagType, ValType,
+    VecType,
+};
 
 /*
 crate::tag::enumeration! {
@@ -27,7 +27,10 @@ pub trait ParseFuncType {
 }
 
 impl<'b, P: ParseFuncType> ParseFuncType for &'b mut P {
-    type ResultType<'a> = P::ResultType<'a> where 'b: 'a;
+    type ResultType<'a>
+        = P::ResultType<'a>
+    where
+        'b: 'a;
 
     #[inline]
     fn parameters(&mut self) -> Self::ResultType<'_> {
@@ -82,9 +85,19 @@ where
     }
 }
 
-const FUNC_TYPE_TAG: u8 = 0x60;
+pub(super) const FUNC_TYPE_TAG: u8 = 0x60;
 
-//fn func_type_no_tag // parse without FUNC_TYPE_TAG
+fn func_type_tag<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, (), E> {
+    if let Some((&FUNC_TYPE_TAG, input)) = input.split_first() {
+        Ok((input, ()))
+    } else {
+        Err(nom::Err::Failure(E::from_error_kind_and_cause(
+            input,
+            error::ErrorKind::Tag,
+            error::ErrorCause::InvalidTag(error::InvalidTag::FuncType(input.first().copied())),
+        )))
+    }
+}
 
 /// Parses a WebAssembly [function type].
 ///
@@ -96,17 +109,55 @@ where
     P: ParseFuncType,
     E: ErrorSource<'a>,
 {
-    let input = if let Some((&FUNC_TYPE_TAG, input)) = input.split_first() {
-        input
-    } else {
-        return Err(nom::Err::Failure(E::from_error_kind_and_cause(
-            input,
-            error::ErrorKind::Tag,
-            error::ErrorCause::InvalidTag(error::InvalidTag::FuncType(input.first().copied())),
-        )));
-    };
-
+    let (input, ()) = func_type_tag(input)?;
     let (input, _) = types::result_type(input, parsers.parameters())?;
     let (input, _) = types::result_type(input, parsers.results())?;
     Ok((input, parsers))
 }
+
+/// Represents a WebAssembly [function type] whose parameter and result types have not yet been
+/// parsed.
+///
+/// To parse a function type into a heap allocation, use [`FuncType`] instead.
+///
+/// [function type]: https://webassembly.github.io/spec/core/binary/types.html#function-types
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct RawFuncType<'a> {
+    /// The parameter types.
+    pub parameters: types::ResultType<'a>,
+    /// The result types.
+    pub results: types::ResultType<'a>,
+}
+
+impl<'a> RawFuncType<'a> {
+    /// Parses a [`RawFuncType`].
+    ///
+    /// # Errors
+    ///
+    /// See the documentation for the [`func_type()`] parser for more information.
+    pub fn parse<E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let (input, ()) = func_type_tag(input)?;
+        let (input, parameters) = types::ResultType::parse(input)?;
+        let (input, results) = types::ResultType::parse(input)?;
+        Ok((
+            input,
+            Self {
+                parameters,
+                results,
+            },
+        ))
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`RawFuncType::parse()`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct RawFuncTypeParser;
+
+impl<'a, E: ErrorSource<'a>> Parser<&'a [u8], RawFuncType<'a>, E> for RawFuncTypeParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> crate::Parsed<'a, RawFuncType<'a>, E> {
+        RawFuncType::parse(input)
+    }
+}
@@ -148,7 +148,10 @@ impl<V: Vector<Item = ValType> + Clone> crate::types::ParseResultType for Builde
 }
 
 impl<V: Vector<Item = ValType> + Clone> ParseFuncType for Builder<V> {
-    type ResultType<'a> = &'a mut Self where V: 'a;
+    type ResultType<'a>
+        = &'a mut Self
+    where
+        V: 'a;
 
     #[inline]
     fn parameters(&mut self) -> Self::ResultType<'_> {
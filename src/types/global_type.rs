@@ -1,4 +1,7 @@
-use crate::types::ValType;
+use crate::{
+    features::WasmFeatures,
+    types::{Sharing, ValType},
+};
 
 /// Indicates whether a WebAssembly [**`global`**] is mutable.
 ///
@@ -32,4 +35,20 @@ pub struct GlobalType {
     pub mutability: Mutability,
     /// The type of the value stored in the global.
     pub value_type: ValType,
+    /// Indicates whether or not the global can be accessed in multiple agents, introduced as part
+    /// of the [shared-everything threads proposal].
+    ///
+    /// [shared-everything threads proposal]: https://github.com/WebAssembly/shared-everything-threads
+    pub share: Sharing,
+}
+
+impl GlobalType {
+    /// Gets the proposals that are required to use this global type.
+    pub const fn required_features(&self) -> WasmFeatures {
+        let features = self.value_type.required_features();
+        match self.share {
+            Sharing::Shared => features.union(WasmFeatures::SHARED_EVERYTHING_THREADS),
+            Sharing::Unshared => features,
+        }
+    }
 }
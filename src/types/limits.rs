@@ -1,4 +1,4 @@
-use crate::types;
+use crate::{features::WasmFeatures, types};
 
 /// Indicates the size of indices into a linear memory or table.
 ///
@@ -11,16 +11,28 @@ pub enum IdxType {
     /// The memory or table is indexed by a 32-bit integer, as it was in the WebAssembly 1.0 release.
     #[default]
     I32,
-    /// The memory, is indexed by a 64-bit integer.
+    /// The memory or table is indexed by a 64-bit integer.
     ///
-    /// This requires the [*memory64* proposal].
-    ///
-    /// At the time of writing, no feature proposal introduces 64-bit indices for tables.
+    /// This requires the [*memory64* proposal], which also introduced 64-bit indices for tables.
     ///
     /// [*memory64* proposal]: https://github.com/WebAssembly/memory64
     I64,
 }
 
+impl IdxType {
+    /// Gets the smaller of two index types.
+    ///
+    /// This is the type of the length operand for instructions that copy between two memories or
+    /// two tables, such as `memory.copy` and `table.copy`.
+    #[inline]
+    pub const fn min(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::I64, Self::I64) => Self::I64,
+            _ => Self::I32,
+        }
+    }
+}
+
 impl From<IdxType> for types::NumType {
     #[inline]
     fn from(index_type: IdxType) -> Self {
@@ -48,7 +60,7 @@ pub enum LimitBounds {
     /// For more information, see the documentation for [`IdxType::I32`].
     #[allow(missing_docs)]
     I32 { min: u32, max: Option<u32> },
-    /// The memory has 64-bit integer bounds.
+    /// The memory or table has 64-bit integer bounds.
     ///
     /// For more information, see the documentation for [`IdxType::I64`].
     #[allow(missing_docs)]
@@ -91,14 +103,20 @@ impl Default for LimitBounds {
     }
 }
 
-/// Indicates whether a linear memory or table is shared, the semantics of which is described in
-/// the [WebAssembly threads proposal](https://github.com/WebAssembly/threads).
+/// Indicates whether a linear memory, table, global, or heap type is shared.
+///
+/// The semantics of shared linear memories are described in the [WebAssembly threads proposal],
+/// while the [shared-everything threads proposal] allows tables, globals, and heap types to be
+/// shared.
+///
+/// [WebAssembly threads proposal]: https://github.com/WebAssembly/threads
+/// [shared-everything threads proposal]: https://github.com/WebAssembly/shared-everything-threads
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 #[allow(clippy::exhaustive_enums)]
 pub enum Sharing {
-    /// The linear memory or table can be used in multiple agents.
+    /// The item can be used in multiple agents.
     Shared,
-    /// The linear memory or table can only be used in a single agent.
+    /// The item can only be used in a single agent.
     #[default]
     Unshared,
 }
@@ -112,4 +130,52 @@ pub struct Limits {
     /// Indicates whether or not the corresponding linear memory or table can be used in multiple
     /// agents.
     pub share: Sharing,
+    /// The base-2 logarithm of the size of a page of the linear memory, or `None` if the
+    /// [default page size](Limits::DEFAULT_PAGE_SIZE) of 64 KiB is used.
+    ///
+    /// Only page sizes of `1` and `65536` bytes are allowed. See the
+    /// [custom page sizes proposal] for more information.
+    ///
+    /// [custom page sizes proposal]: https://github.com/WebAssembly/custom-page-sizes
+    pub page_size_log2: Option<u8>,
+}
+
+impl Limits {
+    /// The size of a page of linear memory, in bytes, if no page size is specified.
+    pub const DEFAULT_PAGE_SIZE: u32 = 65536;
+
+    pub(crate) const FLAG_PAGE_SIZE: u8 = 0b1000;
+    pub(crate) const FLAGS_ALL: u8 = 0b1111;
+
+    /// The size of a page of the linear memory, in bytes.
+    #[inline]
+    pub fn page_size(&self) -> u32 {
+        self.page_size_log2
+            .map_or(Self::DEFAULT_PAGE_SIZE, |log2| 1 << log2)
+    }
+
+    /// The integer type used to index into the linear memory or table.
+    #[inline]
+    pub fn index_type(&self) -> IdxType {
+        self.bounds.index_type()
+    }
+
+    /// Gets the proposals that are required for the index type and page size of the linear memory
+    /// or table.
+    ///
+    /// Whether the limits are [**`shared`**](Limits::share) is not taken into account, since the
+    /// proposal that introduced **`shared`** tables differs from the one for memories.
+    pub fn required_features(&self) -> WasmFeatures {
+        let mut features = WasmFeatures::MVP;
+
+        if self.index_type() == IdxType::I64 {
+            features |= WasmFeatures::MEMORY64;
+        }
+
+        if self.page_size_log2.is_some() {
+            features |= WasmFeatures::CUSTOM_PAGE_SIZES;
+        }
+
+        features
+    }
 }
@@ -0,0 +1,823 @@
+use crate::{
+    error::{self, ErrorCause, ErrorKind, ErrorSource},
+    features::WasmFeatures,
+    index::Index as _,
+    types::{self, func_type::FUNC_TYPE_TAG, Mutability, ParseFuncType, TypeIdx, ValType},
+    values, Parsed,
+};
+use core::fmt::{Display, Formatter};
+
+/// Represents a [packed type], which can only be used to store fields in [struct] and [array]
+/// types.
+///
+/// Introduced as part of the [garbage collection proposal].
+///
+/// [packed type]: https://webassembly.github.io/gc/core/syntax/types.html#syntax-packedtype
+/// [struct]: ParseRecType::struct_type
+/// [array]: ParseRecType::array_type
+/// [garbage collection proposal]: https://github.com/WebAssembly/gc
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum PackedType {
+    /// An 8-bit integer, **`i8`**.
+    I8,
+    /// A 16-bit integer, **`i16`**.
+    I16,
+}
+
+/// Represents a [storage type], which is the type of a field in a [struct] or [array] type.
+///
+/// [storage type]: https://webassembly.github.io/gc/core/syntax/types.html#syntax-storagetype
+/// [struct]: ParseRecType::struct_type
+/// [array]: ParseRecType::array_type
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum StorageType {
+    /// A field storing a value of the given type.
+    Val(ValType),
+    /// A field storing a packed integer.
+    Packed(PackedType),
+}
+
+/// Represents a [field type], which describes the type and mutability of a field in a [struct] or
+/// [array] type.
+///
+/// [field type]: https://webassembly.github.io/gc/core/syntax/types.html#syntax-fieldtype
+/// [struct]: ParseRecType::struct_type
+/// [array]: ParseRecType::array_type
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct FieldType {
+    /// Whether or not the field is mutable.
+    pub mutability: Mutability,
+    /// The type of the value stored in the field.
+    pub storage_type: StorageType,
+}
+
+impl From<ValType> for StorageType {
+    #[inline]
+    fn from(value_type: ValType) -> Self {
+        Self::Val(value_type)
+    }
+}
+
+impl From<PackedType> for StorageType {
+    #[inline]
+    fn from(packed_type: PackedType) -> Self {
+        Self::Packed(packed_type)
+    }
+}
+
+impl Display for PackedType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+        })
+    }
+}
+
+impl Display for StorageType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Val(value_type) => Display::fmt(value_type, f),
+            Self::Packed(packed_type) => Display::fmt(packed_type, f),
+        }
+    }
+}
+
+impl StorageType {
+    #[allow(missing_docs)]
+    pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, Self, E> {
+        match input.split_first() {
+            Some((0x78, input)) => Ok((input, Self::Packed(PackedType::I8))),
+            Some((0x77, input)) => Ok((input, Self::Packed(PackedType::I16))),
+            _ => ValType::parse(input).map(|(input, value_type)| (input, Self::Val(value_type))),
+        }
+    }
+}
+
+impl FieldType {
+    #[allow(missing_docs)]
+    pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, Self, E> {
+        let (input, storage_type) = StorageType::parse(input)?;
+
+        let mutability = match input.first() {
+            Some(0) => Mutability::Constant,
+            Some(1) => Mutability::Variable,
+            bad => {
+                return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                    input,
+                    ErrorKind::OneOf,
+                    ErrorCause::InvalidFlags(error::InvalidFlags::FieldType(match bad {
+                        Some(flags) => error::InvalidFlagsValue::Invalid {
+                            value: *flags,
+                            invalid: flags & (!1u8),
+                        },
+                        None => error::InvalidFlagsValue::Missing,
+                    })),
+                )))
+            }
+        };
+
+        Ok((
+            &input[1..],
+            Self {
+                mutability,
+                storage_type,
+            },
+        ))
+    }
+}
+
+/// Trait for parsing a WebAssembly [recursive type], which is a group of [composite types] that
+/// can refer to each other.
+///
+/// Introduced as part of the [garbage collection proposal].
+///
+/// [recursive type]: https://webassembly.github.io/gc/core/binary/types.html#recursive-types
+/// [composite types]: https://webassembly.github.io/gc/core/binary/types.html#composite-types
+/// [garbage collection proposal]: https://github.com/WebAssembly/gc
+pub trait ParseRecType {
+    /// Handles parsing function types.
+    type FuncType<'a>: ParseFuncType
+    where
+        Self: 'a;
+
+    /// Called at the start of a recursive type, with the number of sub types it contains.
+    ///
+    /// Sub types that are not explicitly placed in a **`rec`** group are treated as being in a
+    /// group by themselves.
+    fn rec_group(&mut self, count: usize);
+
+    /// Called at the start of each sub type, indicating whether it is final and how many
+    /// supertypes it has.
+    ///
+    /// Composite types that are not explicitly encoded with **`sub`** or **`sub final`** are
+    /// final and have no supertypes.
+    fn sub_type(&mut self, is_final: bool, supertype_count: usize);
+
+    /// Called for each supertype of a sub type.
+    ///
+    /// Called after the [`ParseRecType::sub_type()`] method.
+    fn supertype(&mut self, index: TypeIdx);
+
+    /// Called before a composite type that is marked as **`shared`**, introduced as part of the
+    /// [shared-everything threads proposal].
+    ///
+    /// Called after the [`ParseRecType::sub_type()`] method.
+    ///
+    /// [shared-everything threads proposal]: https://github.com/WebAssembly/shared-everything-threads
+    fn shared_type(&mut self);
+
+    /// Handles parsing a function type.
+    fn func_type(&mut self) -> Self::FuncType<'_>;
+
+    /// Called at the start of a struct type, with the number of fields it contains.
+    fn struct_type(&mut self, field_count: usize);
+
+    /// Called for each field of a struct type.
+    ///
+    /// Called after the [`ParseRecType::struct_type()`] method.
+    fn struct_field(&mut self, field: FieldType);
+
+    /// Called when an array type with the given element type is parsed.
+    fn array_type(&mut self, element: FieldType);
+
+    /// Called when a continuation type is parsed, with the index of the function type that
+    /// describes the continuation's parameters and results.
+    ///
+    /// Introduced as part of the [stack switching proposal].
+    ///
+    /// [stack switching proposal]: https://github.com/WebAssembly/stack-switching
+    fn cont_type(&mut self, func_type: TypeIdx);
+}
+
+impl<'b, P: ParseRecType> ParseRecType for &'b mut P {
+    type FuncType<'a>
+        = P::FuncType<'a>
+    where
+        'b: 'a;
+
+    #[inline]
+    fn rec_group(&mut self, count: usize) {
+        P::rec_group(self, count)
+    }
+
+    #[inline]
+    fn sub_type(&mut self, is_final: bool, supertype_count: usize) {
+        P::sub_type(self, is_final, supertype_count)
+    }
+
+    #[inline]
+    fn supertype(&mut self, index: TypeIdx) {
+        P::supertype(self, index)
+    }
+
+    #[inline]
+    fn shared_type(&mut self) {
+        P::shared_type(self)
+    }
+
+    #[inline]
+    fn func_type(&mut self) -> Self::FuncType<'_> {
+        P::func_type(self)
+    }
+
+    #[inline]
+    fn struct_type(&mut self, field_count: usize) {
+        P::struct_type(self, field_count)
+    }
+
+    #[inline]
+    fn struct_field(&mut self, field: FieldType) {
+        P::struct_field(self, field)
+    }
+
+    #[inline]
+    fn array_type(&mut self, element: FieldType) {
+        P::array_type(self, element)
+    }
+
+    #[inline]
+    fn cont_type(&mut self, func_type: TypeIdx) {
+        P::cont_type(self, func_type)
+    }
+}
+
+const REC_TAG: u8 = 0x4E;
+const SUB_TAG: u8 = 0x50;
+const SUB_FINAL_TAG: u8 = 0x4F;
+const STRUCT_TAG: u8 = 0x5F;
+const ARRAY_TAG: u8 = 0x5E;
+const CONT_TAG: u8 = 0x5D;
+const SHARED_TAG: u8 = 0x65;
+
+fn comp_type<'a, P, E>(input: &'a [u8], parser: &mut P) -> Parsed<'a, (), E>
+where
+    P: ParseRecType,
+    E: ErrorSource<'a>,
+{
+    let input = if let Some((&SHARED_TAG, input)) = input.split_first() {
+        parser.shared_type();
+        input
+    } else {
+        input
+    };
+
+    match input.split_first() {
+        Some((&FUNC_TYPE_TAG, _)) => {
+            types::func_type(input, parser.func_type()).map(|(input, _)| (input, ()))
+        }
+        Some((&STRUCT_TAG, input)) => {
+            let (input, count) = values::vector_length(input)?;
+            parser.struct_type(nom::ToUsize::to_usize(&count));
+            values::sequence(input, count, |input| {
+                let (input, field) = FieldType::parse(input)?;
+                parser.struct_field(field);
+                Ok((input, ()))
+            })
+        }
+        Some((&ARRAY_TAG, input)) => {
+            let (input, element) = FieldType::parse(input)?;
+            parser.array_type(element);
+            Ok((input, ()))
+        }
+        Some((&CONT_TAG, input)) => {
+            let (input, func_type) = TypeIdx::parse(input)?;
+            parser.cont_type(func_type);
+            Ok((input, ()))
+        }
+        bad => Err(nom::Err::Failure(E::from_error_kind_and_cause(
+            input,
+            ErrorKind::Tag,
+            ErrorCause::InvalidTag(error::InvalidTag::CompType(bad.map(|(tag, _)| *tag))),
+        ))),
+    }
+}
+
+fn sub_type<'a, P, E>(input: &'a [u8], parser: &mut P) -> Parsed<'a, (), E>
+where
+    P: ParseRecType,
+    E: ErrorSource<'a>,
+{
+    let input = match input.split_first() {
+        Some((tag @ (&SUB_TAG | &SUB_FINAL_TAG), input)) => {
+            let (input, count) = values::vector_length(input)?;
+            parser.sub_type(*tag == SUB_FINAL_TAG, nom::ToUsize::to_usize(&count));
+            let (input, ()) = values::sequence(input, count, |input| {
+                let (input, index) = TypeIdx::parse(input)?;
+                parser.supertype(index);
+                Ok((input, ()))
+            })?;
+            input
+        }
+        _ => {
+            parser.sub_type(true, 0);
+            input
+        }
+    };
+
+    comp_type(input, parser)
+}
+
+/// Parses a WebAssembly [recursive type].
+///
+/// Each sub type in the group is passed to the given [`ParseRecType`] implementation.
+///
+/// [recursive type]: https://webassembly.github.io/gc/core/binary/types.html#recursive-types
+pub fn rec_type<'a, P, E>(input: &'a [u8], mut parser: P) -> Parsed<'a, P, E>
+where
+    P: ParseRecType,
+    E: ErrorSource<'a>,
+{
+    let (input, ()) = if let Some((&REC_TAG, input)) = input.split_first() {
+        let (input, count) = values::vector_length(input)?;
+        parser.rec_group(nom::ToUsize::to_usize(&count));
+        values::sequence(input, count, |input| sub_type(input, &mut parser))?
+    } else {
+        parser.rec_group(1);
+        sub_type(input, &mut parser)?
+    };
+
+    Ok((input, parser))
+}
+
+/// Parses a WebAssembly [recursive type](rec_type()), failing if any of its sub types require a
+/// proposal that is not enabled in the given set of `features`.
+///
+/// The recursive type is checked before any of its sub types are passed to the given
+/// [`ParseRecType`] implementation.
+///
+/// # Errors
+///
+/// Returns an error with an [`ErrorCause::FeatureDisabled`] if a sub type requires a disabled
+/// proposal. See the documentation for [`rec_type()`] for more information.
+pub fn rec_type_with_features<'a, P, E>(
+    input: &'a [u8],
+    features: WasmFeatures,
+    parser: P,
+) -> Parsed<'a, P, E>
+where
+    P: ParseRecType,
+    E: ErrorSource<'a>,
+{
+    let (input, rec_type) = RawRecType::parse_with_features(input, features)?;
+    let parser = rec_type.parse_with(parser)?;
+    Ok((input, parser))
+}
+
+/// A [`ParseRecType`] implementation that ignores everything, used to check that a
+/// [`RawRecType`] is valid.
+struct SkipRecType;
+
+impl types::ParseResultType for SkipRecType {
+    #[inline]
+    fn with_count(&mut self, _: usize) {}
+
+    #[inline]
+    fn next_type(&mut self, _: ValType) {}
+}
+
+impl ParseFuncType for SkipRecType {
+    type ResultType<'a> = Self;
+
+    #[inline]
+    fn parameters(&mut self) -> Self {
+        Self
+    }
+
+    #[inline]
+    fn results(&mut self) -> Self {
+        Self
+    }
+}
+
+impl ParseRecType for SkipRecType {
+    type FuncType<'a> = Self;
+
+    #[inline]
+    fn rec_group(&mut self, _: usize) {}
+
+    #[inline]
+    fn sub_type(&mut self, _: bool, _: usize) {}
+
+    #[inline]
+    fn supertype(&mut self, _: TypeIdx) {}
+
+    #[inline]
+    fn shared_type(&mut self) {}
+
+    #[inline]
+    fn func_type(&mut self) -> Self {
+        Self
+    }
+
+    #[inline]
+    fn struct_type(&mut self, _: usize) {}
+
+    #[inline]
+    fn struct_field(&mut self, _: FieldType) {}
+
+    #[inline]
+    fn array_type(&mut self, _: FieldType) {}
+
+    #[inline]
+    fn cont_type(&mut self, _: TypeIdx) {}
+}
+
+/// A [`ParseRecType`] implementation that collects the proposals required by a [`RawRecType`].
+struct RequiredFeatures(WasmFeatures);
+
+impl RequiredFeatures {
+    fn field(&mut self, field: FieldType) {
+        if let StorageType::Val(value_type) = field.storage_type {
+            self.0 |= value_type.required_features();
+        }
+    }
+}
+
+impl types::ParseResultType for RequiredFeatures {
+    #[inline]
+    fn with_count(&mut self, _: usize) {}
+
+    #[inline]
+    fn next_type(&mut self, value_type: ValType) {
+        self.0 |= value_type.required_features();
+    }
+}
+
+impl ParseFuncType for RequiredFeatures {
+    type ResultType<'a> = &'a mut Self;
+
+    #[inline]
+    fn parameters(&mut self) -> &mut Self {
+        self
+    }
+
+    #[inline]
+    fn results(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl ParseRecType for RequiredFeatures {
+    type FuncType<'a> = &'a mut Self;
+
+    #[inline]
+    fn rec_group(&mut self, _: usize) {}
+
+    fn sub_type(&mut self, is_final: bool, supertype_count: usize) {
+        if !is_final || supertype_count > 0 {
+            self.0 |= WasmFeatures::GC;
+        }
+    }
+
+    #[inline]
+    fn supertype(&mut self, _: TypeIdx) {}
+
+    fn shared_type(&mut self) {
+        self.0 |= WasmFeatures::SHARED_EVERYTHING_THREADS;
+    }
+
+    #[inline]
+    fn func_type(&mut self) -> &mut Self {
+        self
+    }
+
+    fn struct_type(&mut self, _: usize) {
+        self.0 |= WasmFeatures::GC;
+    }
+
+    fn struct_field(&mut self, field: FieldType) {
+        self.field(field);
+    }
+
+    fn array_type(&mut self, element: FieldType) {
+        self.0 |= WasmFeatures::GC;
+        self.field(element);
+    }
+
+    fn cont_type(&mut self, _: TypeIdx) {
+        self.0 |= WasmFeatures::STACK_SWITCHING;
+    }
+}
+
+/// Represents a WebAssembly [recursive type] whose sub types have not yet been parsed.
+///
+/// [recursive type]: https://webassembly.github.io/gc/core/binary/types.html#recursive-types
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[must_use]
+pub struct RawRecType<'a> {
+    count: u32,
+    is_group: bool,
+    sub_types: &'a [u8],
+}
+
+impl<'a> RawRecType<'a> {
+    /// Parses a [`RawRecType`], checking that all of its sub types are valid.
+    ///
+    /// # Errors
+    ///
+    /// See the documentation for [`rec_type()`] for more information.
+    pub fn parse<E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, Self, E> {
+        let (sub_types, count, is_group) = if let Some((&REC_TAG, input)) = input.split_first() {
+            let (input, count) = values::vector_length(input)?;
+            (input, count, true)
+        } else {
+            (input, 1, false)
+        };
+
+        let (remaining, ()) =
+            values::sequence(sub_types, count, |input| sub_type(input, &mut SkipRecType))?;
+
+        Ok((
+            remaining,
+            Self {
+                count,
+                is_group,
+                sub_types: &sub_types[..sub_types.len() - remaining.len()],
+            },
+        ))
+    }
+
+    /// Parses a [`RawRecType`], failing if any of its sub types require a proposal that is not
+    /// enabled in the given set of `features`.
+    ///
+    /// # Errors
+    ///
+    /// See the documentation for [`rec_type_with_features()`] for more information.
+    pub fn parse_with_features<E: ErrorSource<'a>>(
+        input: &'a [u8],
+        features: WasmFeatures,
+    ) -> Parsed<'a, Self, E> {
+        let (remaining, rec_type) = Self::parse(input)?;
+        features.check(input, rec_type.required_features())?;
+        Ok((remaining, rec_type))
+    }
+
+    /// Gets the proposals that are required to use the sub types of this recursive type.
+    ///
+    /// Recursive types other than a single final function type with no supertypes require the
+    /// [garbage collection proposal](WasmFeatures::GC).
+    pub fn required_features(&self) -> WasmFeatures {
+        let mut required = RequiredFeatures(WasmFeatures::MVP);
+        if self.is_group {
+            required.0 |= WasmFeatures::GC;
+        }
+
+        // The sub types were already checked when the recursive type was parsed
+        let _ = self.parse_with::<_, error::Error>(&mut required);
+        required.0
+    }
+
+    /// The number of sub types.
+    #[inline]
+    pub fn len(&self) -> usize {
+        nom::ToUsize::to_usize(&self.count)
+    }
+
+    /// Returns `true` if there are no sub types.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns `true` if the sub types were explicitly placed in a **`rec`** group.
+    #[inline]
+    pub fn is_group(&self) -> bool {
+        self.is_group
+    }
+
+    /// Gets the function type if this recursive type is a single function type that is not in a
+    /// **`rec`** group, and is final with no supertypes.
+    ///
+    /// These are the only types that can be defined without the [garbage collection proposal].
+    ///
+    /// [garbage collection proposal]: https://github.com/WebAssembly/gc
+    pub fn as_func_type(&self) -> Option<types::RawFuncType<'a>> {
+        match self.sub_types.first() {
+            Some(&FUNC_TYPE_TAG) if !self.is_group => {
+                types::RawFuncType::parse::<error::Error>(self.sub_types)
+                    .ok()
+                    .map(|(_, func_type)| func_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses the sub types with the given [`ParseRecType`] implementation.
+    ///
+    /// # Errors
+    ///
+    /// See the documentation for [`rec_type()`] for more information.
+    pub fn parse_with<P, E>(&self, mut parser: P) -> crate::input::Result<P, E>
+    where
+        P: ParseRecType,
+        E: ErrorSource<'a>,
+    {
+        parser.rec_group(self.len());
+        values::sequence(self.sub_types, self.count, |input| {
+            sub_type(input, &mut parser)
+        })?;
+        Ok(parser)
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for RawRecType<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.sub_types
+    }
+}
+
+/// Writes a [`RawRecType`] in the WebAssembly text format.
+struct TextRecType<'f, 'g> {
+    f: &'f mut Formatter<'g>,
+    is_group: bool,
+    /// The number of parentheses to close at the end of the current sub type.
+    open: usize,
+    result: core::fmt::Result,
+}
+
+impl TextRecType<'_, '_> {
+    fn write(&mut self, args: core::fmt::Arguments) {
+        if self.result.is_ok() {
+            self.result = self.f.write_fmt(args);
+        }
+    }
+
+    /// Writes a space if the composite type is nested in a **`sub`** or **`shared`** type.
+    fn separate(&mut self) {
+        if self.open > 0 {
+            self.write(format_args!(" "));
+        }
+    }
+
+    fn field(&mut self, field: FieldType) {
+        match field.mutability {
+            Mutability::Constant => self.write(format_args!("{}", field.storage_type)),
+            Mutability::Variable => self.write(format_args!("(mut {})", field.storage_type)),
+        }
+    }
+
+    fn close(&mut self) {
+        for _ in 0..self.open {
+            self.write(format_args!(")"));
+        }
+
+        self.open = 0;
+    }
+}
+
+impl types::ParseResultType for TextRecType<'_, '_> {
+    #[inline]
+    fn with_count(&mut self, _: usize) {}
+
+    fn next_type(&mut self, value_type: ValType) {
+        self.write(format_args!(" {value_type}"));
+    }
+}
+
+impl<'f, 'g> ParseFuncType for TextRecType<'f, 'g> {
+    type ResultType<'a>
+        = &'a mut Self
+    where
+        Self: 'a;
+
+    fn parameters(&mut self) -> &mut Self {
+        self.write(format_args!(" (param"));
+        self.open += 1;
+        self
+    }
+
+    fn results(&mut self) -> &mut Self {
+        self.write(format_args!(") (result"));
+        self
+    }
+}
+
+impl<'f, 'g> ParseRecType for TextRecType<'f, 'g> {
+    type FuncType<'a>
+        = &'a mut Self
+    where
+        Self: 'a;
+
+    #[inline]
+    fn rec_group(&mut self, _: usize) {}
+
+    fn sub_type(&mut self, is_final: bool, supertype_count: usize) {
+        self.close();
+
+        if self.is_group {
+            self.write(format_args!(" "));
+        }
+
+        if !is_final || supertype_count > 0 {
+            let sub = if is_final { "sub final" } else { "sub" };
+            self.write(format_args!("({sub}"));
+            self.open += 1;
+        }
+    }
+
+    fn supertype(&mut self, index: TypeIdx) {
+        self.write(format_args!(" {index}"));
+    }
+
+    fn shared_type(&mut self) {
+        self.separate();
+        self.write(format_args!("(shared"));
+        self.open += 1;
+    }
+
+    fn func_type(&mut self) -> &mut Self {
+        self.separate();
+        self.write(format_args!("(func"));
+        self.open += 1;
+        self
+    }
+
+    fn struct_type(&mut self, _: usize) {
+        self.separate();
+        self.write(format_args!("(struct"));
+        self.open += 1;
+    }
+
+    fn struct_field(&mut self, field: FieldType) {
+        self.write(format_args!(" (field "));
+        self.field(field);
+        self.write(format_args!(")"));
+    }
+
+    fn array_type(&mut self, element: FieldType) {
+        self.separate();
+        self.write(format_args!("(array "));
+        self.field(element);
+        self.write(format_args!(")"));
+    }
+
+    fn cont_type(&mut self, func_type: TypeIdx) {
+        self.separate();
+        self.write(format_args!("(cont {func_type})"));
+    }
+}
+
+impl Display for RawRecType<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_group {
+            f.write_str("(rec")?;
+        }
+
+        let mut text = TextRecType {
+            f,
+            is_group: self.is_group,
+            open: 0,
+            result: Ok(()),
+        };
+
+        if let Err(err) = self.parse_with::<_, error::Error>(&mut text) {
+            text.close();
+            text.write(format_args!(" {err:?}"));
+        }
+
+        text.close();
+        text.result?;
+
+        if self.is_group {
+            f.write_str(")")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for RawRecType<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RawRecType")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+/// Provides a [`nom::Parser`] implementation for [`RawRecType::parse_with_features()`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct RawRecTypeParser {
+    /// The set of proposals that parsed recursive types are allowed to use.
+    pub features: WasmFeatures,
+}
+
+impl RawRecTypeParser {
+    /// Creates a parser that only accepts recursive types allowed by the given `features`.
+    #[inline]
+    pub const fn with_features(features: WasmFeatures) -> Self {
+        Self { features }
+    }
+}
+
+impl<'a, E: ErrorSource<'a>> nom::Parser<&'a [u8], RawRecType<'a>, E> for RawRecTypeParser {
+    #[inline]
+    fn parse(&mut self, input: &'a [u8]) -> Parsed<'a, RawRecType<'a>, E> {
+        RawRecType::parse_with_features(input, self.features)
+    }
+}
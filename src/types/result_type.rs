@@ -1,4 +1,9 @@
-use crate::{error::ErrorSource, types::ValType, values};
+use crate::{
+    error::{self, ErrorSource},
+    features::WasmFeatures,
+    types::{ValType, ValTypeParser},
+    values::{self, VectorIter},
+};
 
 /// Trait for parsing a WebAssembly [result type].
 ///
@@ -43,3 +48,79 @@ where
     })
     .map(|(input, ())| (input, parser))
 }
+
+/// Represents a WebAssembly [result type] whose types have not yet been parsed.
+///
+/// [result type]: https://webassembly.github.io/spec/core/binary/types.html#result-types
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[must_use]
+pub struct ResultType<'a> {
+    count: u32,
+    types: &'a [u8],
+}
+
+impl<'a> ResultType<'a> {
+    /// Parses a [`ResultType`], checking that all of its types are valid.
+    ///
+    /// # Errors
+    ///
+    /// See the documentation for [`result_type()`] for more information.
+    pub fn parse<E: ErrorSource<'a>>(input: &'a [u8]) -> crate::Parsed<'a, Self, E> {
+        let (types, count) = values::vector_length(input)?;
+        let (remaining, _) = VectorIter::<_, E, _>::new(
+            count,
+            types,
+            ValTypeParser::with_features(WasmFeatures::ALL),
+        )
+        .finish()?;
+        Ok((
+            remaining,
+            Self {
+                count,
+                types: &types[..types.len() - remaining.len()],
+            },
+        ))
+    }
+
+    /// The number of types.
+    #[inline]
+    pub fn len(&self) -> usize {
+        nom::ToUsize::to_usize(&self.count)
+    }
+
+    /// Returns `true` if there are no types.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns an [`Iterator`] over the types.
+    #[inline]
+    pub fn iter<E: ErrorSource<'a>>(&self) -> VectorIter<'a, ValType, E, ValTypeParser> {
+        VectorIter::new(
+            self.count,
+            self.types,
+            ValTypeParser::with_features(WasmFeatures::ALL),
+        )
+    }
+}
+
+impl<'a> crate::input::AsInput<'a> for ResultType<'a> {
+    #[inline]
+    fn as_input(&self) -> &'a [u8] {
+        self.types
+    }
+}
+
+impl core::fmt::Debug for ResultType<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        for result in self.iter::<error::Error>() {
+            match result {
+                Ok(value_type) => list.entry(&value_type),
+                Err(err) => list.entry(&err),
+            };
+        }
+        list.finish()
+    }
+}
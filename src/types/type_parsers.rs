@@ -1,11 +1,98 @@
 use crate::{
     error::{self, AddCause, ErrorCause, ErrorKind, ErrorSource},
-    types::{self, BlockType, Limits, ValType},
+    features::WasmFeatures,
+    types::{self, AbstractHeapType, BlockType, HeapType, Limits, RefType, ValType},
     values::leb128,
     Parsed,
 };
 use nom::Parser;
 
+/// Encoding for a non-nullable **`(ref ht)`** reference type (`0x64`).
+const REF: i64 = -28;
+
+/// Encoding for a nullable **`(ref null ht)`** reference type (`0x63`).
+const REF_NULL: i64 = -29;
+
+/// Encoding for a **`shared`** abstract heap type (`0x65`), introduced as part of the
+/// shared-everything threads proposal.
+///
+/// When used as a reference type, this is a shorthand for **`(ref null (shared absheaptype))`**.
+const SHARED: i64 = -27;
+
+/// Gets the [`AbstractHeapType`] corresponding to a negative signed 33-bit integer.
+fn abstract_heap_type(value: i64) -> Option<AbstractHeapType> {
+    Some(match value {
+        -11 => AbstractHeapType::NoCont,
+        -12 => AbstractHeapType::NoExn,
+        -13 => AbstractHeapType::NoFunc,
+        -14 => AbstractHeapType::NoExtern,
+        -15 => AbstractHeapType::None,
+        -16 => AbstractHeapType::Func,
+        -17 => AbstractHeapType::Extern,
+        -18 => AbstractHeapType::Any,
+        -19 => AbstractHeapType::Eq,
+        -20 => AbstractHeapType::I31,
+        -21 => AbstractHeapType::Struct,
+        -22 => AbstractHeapType::Array,
+        -23 => AbstractHeapType::Exn,
+        -24 => AbstractHeapType::Cont,
+        _ => return None,
+    })
+}
+
+impl HeapType {
+    /// Parses a [`HeapType`], which is encoded as a signed 33-bit integer, optionally preceded by
+    /// a `0x65` byte for **`shared`** abstract heap types.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an unrecognized abstract heap type was encountered, or an encoded type
+    /// index is greater than the maximum value for 32-bit indices.
+    pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, Self, E> {
+        let start = input;
+        let (input, value) = leb128::s64(input).add_cause(ErrorCause::HeapType(None))?;
+
+        if value == SHARED {
+            let shared_start = input;
+            let (input, value) = leb128::s64(input).add_cause(ErrorCause::HeapType(None))?;
+            if let Some(abstract_type) = abstract_heap_type(value) {
+                Ok((
+                    input,
+                    Self::Abstract {
+                        share: types::Sharing::Shared,
+                        abstract_type,
+                    },
+                ))
+            } else {
+                Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                    shared_start,
+                    ErrorKind::Tag,
+                    ErrorCause::HeapType(core::num::NonZeroI64::new(value)),
+                )))
+            }
+        } else if value < 0 {
+            if let Some(abstract_type) = abstract_heap_type(value) {
+                Ok((input, Self::unshared(abstract_type)))
+            } else {
+                Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                    start,
+                    ErrorKind::Tag,
+                    ErrorCause::HeapType(core::num::NonZeroI64::new(value)),
+                )))
+            }
+        } else if let Ok(index) = u32::try_from(value) {
+            Ok((input, Self::Index(index.into())))
+        } else {
+            // Type index too large
+            Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                start,
+                ErrorKind::Verify,
+                ErrorCause::HeapType(core::num::NonZeroI64::new(value)),
+            )))
+        }
+    }
+}
+
 impl BlockType {
     /// Parses a [`BlockType`].
     ///
@@ -24,9 +111,25 @@ impl BlockType {
             -3 => Self::Inline(ValType::F32),
             -4 => Self::Inline(ValType::F64),
             -5 => Self::Inline(ValType::V128),
-            -16 => Self::Inline(ValType::FuncRef),
-            -17 => Self::Inline(ValType::ExternRef),
+            REF | REF_NULL => {
+                let (input, heap_type) = HeapType::parse(input)?;
+                let ref_type = RefType::new(value == REF_NULL, heap_type);
+                return Ok((input, Self::Inline(ValType::Ref(ref_type))));
+            }
+            SHARED => {
+                let (input, heap_type) = HeapType::parse(start)?;
+                let ref_type = RefType::new(true, heap_type);
+                return Ok((input, Self::Inline(ValType::Ref(ref_type))));
+            }
             _ if value < 0 => {
+                if let Some(abstract_type) = abstract_heap_type(value) {
+                    let heap_type = HeapType::unshared(abstract_type);
+                    return Ok((
+                        input,
+                        Self::Inline(ValType::Ref(RefType::new(true, heap_type))),
+                    ));
+                }
+
                 // Unknown
                 return Err(nom::Err::Failure(E::from_error_kind_and_cause(
                     start,
@@ -80,43 +183,128 @@ impl ValType {
     }
 }
 
-impl types::RefType {
-    /// Parses a [`RefType`](types::RefType).
-    ///
-    /// See the documentation for [`ValType::parse()`] for more information.
+impl RefType {
+    /// Parses a [`RefType`], which is either one of the shorthands for nullable abstract heap
+    /// types (such as **`funcref`**, optionally preceded by a `0x65` byte for **`shared`** heap
+    /// types), or a **`(ref null? ht)`** reference type.
     ///
     /// # Errors
     ///
     /// Returns an error if some other [`ValType`] is parsed instead, or if the type was not
     /// encoded correctly.
     pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, Self, E> {
-        match ValType::parse(input)? {
-            (input, ValType::FuncRef) => Ok((input, Self::Func)),
-            (input, ValType::ExternRef) => Ok((input, Self::Extern)),
-            (_, bad) => Err(nom::Err::Failure(E::from_error_kind_and_cause(
-                input,
-                ErrorKind::Verify,
-                ErrorCause::RefType(bad),
-            ))),
+        let start = input;
+        let (input, value) = leb128::s64(input).add_cause(ErrorCause::RefType(None))?;
+
+        match value {
+            REF | REF_NULL => {
+                let (input, heap_type) = HeapType::parse(input)?;
+                Ok((input, Self::new(value == REF_NULL, heap_type)))
+            }
+            SHARED => {
+                let (input, heap_type) = HeapType::parse(start)?;
+                Ok((input, Self::new(true, heap_type)))
+            }
+            _ => match abstract_heap_type(value) {
+                Some(abstract_type) => Ok((input, Self::new(true, abstract_type.into()))),
+                None => Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                    start,
+                    ErrorKind::Verify,
+                    ErrorCause::RefType(core::num::NonZeroI64::new(value)),
+                ))),
+            },
         }
     }
 }
 
-/// Provides an explicit [`Parser`] implementation for [`ValType::parse()`].
-#[derive(Clone, Copy, Debug, Default)]
-#[non_exhaustive]
-pub struct ValTypeParser;
+macro_rules! parse_with_features {
+    ($($ty:ty => $name:literal,)*) => {$(
+        impl $ty {
+            #[doc = concat!("Parses a [`", $name, "`](Self), failing if it requires any proposals that are not enabled in")]
+            /// the given set of `features`.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error with an [`ErrorCause::FeatureDisabled`] if the parsed type
+            /// requires a disabled proposal, or any other error that `parse()` would return.
+            pub fn parse_with_features<'a, E: ErrorSource<'a>>(
+                input: &'a [u8],
+                features: WasmFeatures,
+            ) -> Parsed<'a, Self, E> {
+                let (remaining, parsed) = Self::parse(input)?;
+                features.check(input, parsed.required_features())?;
+                Ok((remaining, parsed))
+            }
+        }
+    )*};
+}
 
-impl<'a, E: ErrorSource<'a>> Parser<&'a [u8], ValType, E> for ValTypeParser {
-    #[inline]
-    fn parse(&mut self, input: &'a [u8]) -> nom::IResult<&'a [u8], ValType, E> {
-        ValType::parse(input)
-    }
+parse_with_features! {
+    HeapType => "HeapType",
+    RefType => "RefType",
+    BlockType => "BlockType",
+    ValType => "ValType",
+    types::MemType => "MemType",
+    types::TableType => "TableType",
+    types::GlobalType => "GlobalType",
+    types::TagType => "TagType",
+}
+
+macro_rules! type_parser {
+    ($(
+        $(#[$meta:meta])*
+        $parser:ident => $ty:ty;
+    )*) => {$(
+        $(#[$meta])*
+        ///
+        /// Types that require proposals not enabled in the parser's [`WasmFeatures`] are rejected.
+        #[derive(Clone, Copy, Debug)]
+        #[non_exhaustive]
+        pub struct $parser {
+            /// The set of proposals that parsed types are allowed to use.
+            pub features: WasmFeatures,
+        }
+
+        impl $parser {
+            /// Creates a parser that only accepts types allowed by the given `features`.
+            #[inline]
+            pub const fn with_features(features: WasmFeatures) -> Self {
+                Self { features }
+            }
+        }
+
+        impl<'a, E: ErrorSource<'a>> Parser<&'a [u8], $ty, E> for $parser {
+            #[inline]
+            fn parse(&mut self, input: &'a [u8]) -> nom::IResult<&'a [u8], $ty, E> {
+                <$ty>::parse_with_features(input, self.features)
+            }
+        }
+    )*};
+}
+
+type_parser! {
+    /// Provides an explicit [`Parser`] implementation for [`ValType::parse()`].
+    ValTypeParser => ValType;
+    /// Provides an explicit [`Parser`] implementation for [`MemType::parse()`].
+    ///
+    /// [`MemType::parse()`]: types::MemType::parse()
+    MemTypeParser => types::MemType;
+    /// Provides an explicit [`Parser`] implementation for [`TagType::parse()`].
+    ///
+    /// [`TagType::parse()`]: types::TagType::parse()
+    TagTypeParser => types::TagType;
 }
 
 impl Limits {
-    #[allow(missing_docs)]
+    /// Parses [`Limits`], which are encoded as flags followed by the minimum, the optional
+    /// maximum, and the optional page size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the flags are invalid, if the bounds could not be parsed, or if a page
+    /// size other than `1` or `65536` was specified.
     pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, Self, E> {
+        let start = input;
         let (input, flags) = if let Some((first, input)) = input.split_first() {
             (input, *first)
         } else {
@@ -129,12 +317,10 @@ impl Limits {
             )));
         };
 
-        const VALID_MASK: u8 = 0b111;
-
-        let invalid = flags & (!VALID_MASK);
+        let invalid = flags & (!Self::FLAGS_ALL);
         if invalid != 0 {
             return Err(nom::Err::Failure(E::from_error_kind_and_cause(
-                &input[..1],
+                &start[..1],
                 ErrorKind::Verify,
                 ErrorCause::InvalidFlags(error::InvalidFlags::Limits(
                     error::InvalidFlagsValue::Invalid {
@@ -177,7 +363,7 @@ impl Limits {
         let (input, bounds) = if flags & USE_MEMORY_64 == 0 {
             parse_bounds!(u32 => I32)
         } else {
-            parse_bounds!(u64 => I64) // memory64
+            parse_bounds!(u64 => I64) // memory64 or table64
         };
 
         const IS_SHARED: u8 = 0b10;
@@ -188,15 +374,53 @@ impl Limits {
             types::Sharing::Shared
         };
 
-        Ok((input, Self { bounds, share }))
+        let (input, page_size_log2) = if flags & Self::FLAG_PAGE_SIZE == 0 {
+            (input, None)
+        } else {
+            let page_size_start = input;
+            let (input, page_size_log2) = leb128::u32(input).add_cause(ErrorCause::Limits {
+                index_type: bounds.index_type(),
+                component: error::LimitsComponent::PageSize,
+            })?;
+
+            let page_size_log2 = match page_size_log2 {
+                0 => 0,
+                16 => 16,
+                _ => {
+                    return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                        page_size_start,
+                        ErrorKind::Verify,
+                        ErrorCause::InvalidFlags(error::InvalidFlags::Limits(
+                            error::InvalidFlagsValue::Invalid {
+                                value: flags,
+                                invalid: Self::FLAG_PAGE_SIZE,
+                            },
+                        )),
+                    )))
+                }
+            };
+
+            (input, Some(page_size_log2))
+        };
+
+        Ok((
+            input,
+            Self {
+                bounds,
+                share,
+                page_size_log2,
+            },
+        ))
     }
 }
 
 impl types::GlobalType {
-    #[allow(missing_docs)]
+    /// Parses a [`GlobalType`](types::GlobalType), which is encoded as a [`ValType`] followed by
+    /// flags indicating whether the global is mutable or **`shared`**.
     pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, Self, E> {
         let (input, value_type) = ValType::parse(input).add_cause(ErrorCause::GlobalType)?;
 
+        let start = input;
         let (input, flags) = if let Some((first, input)) = input.split_first() {
             (input, *first)
         } else {
@@ -209,21 +433,33 @@ impl types::GlobalType {
             )));
         };
 
-        let mutability = match flags {
-            0 => types::Mutability::Constant,
-            1 => types::Mutability::Variable,
-            _ => {
-                return Err(nom::Err::Failure(E::from_error_kind_and_cause(
-                    &input[..1],
-                    ErrorKind::OneOf,
-                    ErrorCause::InvalidFlags(error::InvalidFlags::GlobalType(
-                        error::InvalidFlagsValue::Invalid {
-                            value: flags,
-                            invalid: flags & (!1u8),
-                        },
-                    )),
-                )))
-            }
+        const IS_MUTABLE: u8 = 1;
+        const IS_SHARED: u8 = 0b10;
+
+        let invalid = flags & !(IS_MUTABLE | IS_SHARED);
+        if invalid != 0 {
+            return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                &start[..1],
+                ErrorKind::OneOf,
+                ErrorCause::InvalidFlags(error::InvalidFlags::GlobalType(
+                    error::InvalidFlagsValue::Invalid {
+                        value: flags,
+                        invalid,
+                    },
+                )),
+            )));
+        }
+
+        let mutability = if flags & IS_MUTABLE == 0 {
+            types::Mutability::Constant
+        } else {
+            types::Mutability::Variable
+        };
+
+        let share = if flags & IS_SHARED == 0 {
+            types::Sharing::Unshared
+        } else {
+            types::Sharing::Shared
         };
 
         Ok((
@@ -231,6 +467,7 @@ impl types::GlobalType {
             Self {
                 mutability,
                 value_type,
+                share,
             },
         ))
     }
@@ -250,8 +487,21 @@ impl types::MemType {
 impl types::TableType {
     #[allow(missing_docs)]
     pub fn parse<'a, E: ErrorSource<'a>>(input: &'a [u8]) -> Parsed<'a, Self, E> {
-        let (input, element_type) =
-            types::RefType::parse(input).add_cause(ErrorCause::TableType)?;
+        let (input, element_type) = RefType::parse(input).add_cause(ErrorCause::TableType)?;
+
+        if let Some(flags) = input.first().filter(|f| *f & Limits::FLAG_PAGE_SIZE != 0) {
+            // Custom page sizes only apply to linear memories
+            return Err(nom::Err::Failure(E::from_error_kind_and_cause(
+                &input[..1],
+                ErrorKind::Verify,
+                ErrorCause::InvalidFlags(error::InvalidFlags::Limits(
+                    error::InvalidFlagsValue::Invalid {
+                        value: *flags,
+                        invalid: Limits::FLAG_PAGE_SIZE,
+                    },
+                )),
+            )));
+        }
 
         let (input, limits) = Limits::parse(input).add_cause(ErrorCause::TableType)?;
 
@@ -1,4 +1,7 @@
-use crate::types::{self, TypeIdx};
+use crate::{
+    features::WasmFeatures,
+    types::{self, TypeIdx},
+};
 use core::fmt::{Display, Formatter};
 
 /// Represents a [WebAssembly number type].
@@ -39,22 +42,188 @@ pub enum VecType {
     V128,
 }
 
+/// Represents an [abstract heap type], which is a [`HeapType`] that is not defined in the
+/// *type section*.
+///
+/// Abstract heap types other than [`AbstractHeapType::Func`] and [`AbstractHeapType::Extern`]
+/// were introduced as part of the [function references], [garbage collection],
+/// [exception handling], and [stack switching] proposals.
+///
+/// [abstract heap type]: https://webassembly.github.io/gc/core/syntax/types.html#heap-types
+/// [function references]: https://github.com/WebAssembly/function-references
+/// [garbage collection]: https://github.com/WebAssembly/gc
+/// [exception handling]: https://github.com/WebAssembly/exception-handling
+/// [stack switching]: https://github.com/WebAssembly/stack-switching
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum AbstractHeapType {
+    /// **`func`**, the type of all function references.
+    Func,
+    /// **`extern`**, the type of all external references provided by the WebAssembly embedder.
+    Extern,
+    /// **`any`**, the type of all internal references.
+    Any,
+    /// **`eq`**, the type of all references that can be compared for equality.
+    Eq,
+    /// **`i31`**, the type of unboxed 31-bit scalars.
+    I31,
+    /// **`struct`**, the type of all references to structs.
+    Struct,
+    /// **`array`**, the type of all references to arrays.
+    Array,
+    /// **`exn`**, the type of all exception references.
+    Exn,
+    /// **`cont`**, the type of all continuation references.
+    Cont,
+    /// **`none`**, the bottom type of internal references.
+    None,
+    /// **`nofunc`**, the bottom type of function references.
+    NoFunc,
+    /// **`noextern`**, the bottom type of external references.
+    NoExtern,
+    /// **`noexn`**, the bottom type of exception references.
+    NoExn,
+    /// **`nocont`**, the bottom type of continuation references.
+    NoCont,
+}
+
+impl AbstractHeapType {
+    /// Gets the proposals that introduced this abstract heap type.
+    pub const fn required_features(self) -> WasmFeatures {
+        match self {
+            Self::Func => WasmFeatures::MVP,
+            Self::Extern => WasmFeatures::REFERENCE_TYPES,
+            Self::Any
+            | Self::Eq
+            | Self::I31
+            | Self::Struct
+            | Self::Array
+            | Self::None
+            | Self::NoFunc
+            | Self::NoExtern => WasmFeatures::GC,
+            Self::Exn | Self::NoExn => WasmFeatures::EXCEPTION_HANDLING,
+            Self::Cont | Self::NoCont => WasmFeatures::STACK_SWITCHING,
+        }
+    }
+}
+
+/// Represents a [WebAssembly heap type], which describes what a [`RefType`] refers to.
+///
+/// [WebAssembly heap type]: https://webassembly.github.io/gc/core/syntax/types.html#heap-types
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum HeapType {
+    /// An [abstract heap type](AbstractHeapType).
+    Abstract {
+        /// Whether the heap type is **`shared`**, introduced as part of the
+        /// [shared-everything threads proposal].
+        ///
+        /// [shared-everything threads proposal]: https://github.com/WebAssembly/shared-everything-threads
+        share: types::Sharing,
+        #[allow(missing_docs)]
+        abstract_type: AbstractHeapType,
+    },
+    /// A concrete heap type, defined in the *type section*.
+    Index(TypeIdx),
+}
+
+impl HeapType {
+    /// Creates an abstract [`HeapType`] that is not **`shared`**.
+    #[inline]
+    pub const fn unshared(abstract_type: AbstractHeapType) -> Self {
+        Self::Abstract {
+            share: types::Sharing::Unshared,
+            abstract_type,
+        }
+    }
+
+    /// Gets the proposals that are required to use this heap type.
+    pub const fn required_features(&self) -> WasmFeatures {
+        match self {
+            Self::Abstract {
+                share: types::Sharing::Unshared,
+                abstract_type,
+            } => abstract_type.required_features(),
+            Self::Abstract {
+                share: types::Sharing::Shared,
+                abstract_type,
+            } => abstract_type
+                .required_features()
+                .union(WasmFeatures::SHARED_EVERYTHING_THREADS),
+            Self::Index(_) => WasmFeatures::FUNCTION_REFERENCES,
+        }
+    }
+}
+
+impl From<AbstractHeapType> for HeapType {
+    #[inline]
+    fn from(abstract_type: AbstractHeapType) -> Self {
+        Self::unshared(abstract_type)
+    }
+}
+
+impl From<TypeIdx> for HeapType {
+    #[inline]
+    fn from(index: TypeIdx) -> Self {
+        Self::Index(index)
+    }
+}
+
 /// Represents a [WebAssembly reference type].
 ///
 /// [WebAssembly reference type]: https://webassembly.github.io/spec/core/syntax/types.html#reference-types
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-#[non_exhaustive]
-pub enum RefType {
-    /// A **`funcref`**, a reference to a function.
+pub struct RefType {
+    /// Whether or not the reference can be **`null`**.
+    pub nullable: bool,
+    /// The type of what is referred to.
+    pub heap_type: HeapType,
+}
+
+impl RefType {
+    /// A **`funcref`**, a nullable reference to a function.
     ///
     /// This type was originally known as **`anyfunc`** in the 2017 WebAssembly MVP.
-    Func,
-    /// An **`externref`**, an opaque reference to some object provided by the WebAssembly embedder.
+    pub const FUNCREF: Self = Self::new(true, HeapType::unshared(AbstractHeapType::Func));
+
+    /// An **`externref`**, a nullable opaque reference to some object provided by the WebAssembly
+    /// embedder.
     ///
     /// Introduced as part of the [reference types proposal].
     ///
     /// [reference types proposal]: https://github.com/WebAssembly/reference-types
-    Extern,
+    pub const EXTERNREF: Self = Self::new(true, HeapType::unshared(AbstractHeapType::Extern));
+
+    /// An **`exnref`**, a nullable reference to an exception.
+    ///
+    /// Introduced as part of the [exception handling proposal].
+    ///
+    /// [exception handling proposal]: https://github.com/WebAssembly/exception-handling
+    pub const EXNREF: Self = Self::new(true, HeapType::unshared(AbstractHeapType::Exn));
+
+    /// Creates a new [`RefType`].
+    #[inline]
+    pub const fn new(nullable: bool, heap_type: HeapType) -> Self {
+        Self {
+            nullable,
+            heap_type,
+        }
+    }
+
+    /// Gets the proposals that are required to use this reference type.
+    ///
+    /// Reference types other than the nullable shorthands (such as **`funcref`**) require the
+    /// [function references proposal](WasmFeatures::FUNCTION_REFERENCES).
+    pub const fn required_features(&self) -> WasmFeatures {
+        let heap_type = self.heap_type.required_features();
+        match self.heap_type {
+            HeapType::Abstract {
+                share: types::Sharing::Unshared,
+                ..
+            } if self.nullable => heap_type,
+            _ => heap_type.union(WasmFeatures::FUNCTION_REFERENCES),
+        }
+    }
 }
 
 /// Represents a [WebAssembly value type], which indicate the types of values.
@@ -71,12 +240,35 @@ pub enum ValType {
     F32,
     /// The [**`f64`**](NumType::F64) numeric type.
     F64,
-    /// The [**`funcref`**](RefType::Func) type.
-    FuncRef,
-    /// The [**`externref`**](RefType::Extern) type.
-    ExternRef,
     /// The [**`v128`**](VecType::V128) type.
     V128,
+    /// A reference type.
+    Ref(RefType),
+}
+
+impl ValType {
+    /// The [**`funcref`**](RefType::FUNCREF) type.
+    pub const FUNCREF: Self = Self::Ref(RefType::FUNCREF);
+
+    /// The [**`externref`**](RefType::EXTERNREF) type.
+    pub const EXTERNREF: Self = Self::Ref(RefType::EXTERNREF);
+
+    /// The [**`exnref`**](RefType::EXNREF) type.
+    pub const EXNREF: Self = Self::Ref(RefType::EXNREF);
+
+    /// Gets the proposals that are required to use this value type.
+    ///
+    /// Using reference types as value types requires the
+    /// [reference types proposal](WasmFeatures::REFERENCE_TYPES).
+    pub const fn required_features(&self) -> WasmFeatures {
+        match self {
+            Self::I32 | Self::I64 | Self::F32 | Self::F64 => WasmFeatures::MVP,
+            Self::V128 => WasmFeatures::SIMD128,
+            Self::Ref(ref_type) => ref_type
+                .required_features()
+                .union(WasmFeatures::REFERENCE_TYPES),
+        }
+    }
 }
 
 /// Represents a [**`blocktype`**] which describes the types of the inputs and results of a [block].
@@ -95,6 +287,20 @@ pub enum BlockType {
     Inline(ValType),
 }
 
+impl BlockType {
+    /// Gets the proposals that are required to use this block type.
+    ///
+    /// Block types that refer to a function type require the
+    /// [multi-value proposal](WasmFeatures::MULTI_VALUE).
+    pub const fn required_features(&self) -> WasmFeatures {
+        match self {
+            Self::Empty => WasmFeatures::MVP,
+            Self::Index(_) => WasmFeatures::MULTI_VALUE,
+            Self::Inline(value_type) => value_type.required_features(),
+        }
+    }
+}
+
 impl From<NumType> for ValType {
     fn from(ty: NumType) -> Self {
         match ty {
@@ -107,11 +313,9 @@ impl From<NumType> for ValType {
 }
 
 impl From<RefType> for ValType {
+    #[inline]
     fn from(ty: RefType) -> Self {
-        match ty {
-            RefType::Extern => Self::ExternRef,
-            RefType::Func => Self::FuncRef,
-        }
+        Self::Ref(ty)
     }
 }
 
@@ -142,9 +346,8 @@ impl Display for ValType {
             Self::I64 => "i64",
             Self::F32 => "f32",
             Self::F64 => "f64",
-            Self::FuncRef => "funcref",
-            Self::ExternRef => "externref",
             Self::V128 => "v128",
+            Self::Ref(ref_type) => return Display::fmt(ref_type, f),
         })
     }
 }
@@ -155,9 +358,76 @@ impl Display for NumType {
     }
 }
 
+impl Display for AbstractHeapType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Func => "func",
+            Self::Extern => "extern",
+            Self::Any => "any",
+            Self::Eq => "eq",
+            Self::I31 => "i31",
+            Self::Struct => "struct",
+            Self::Array => "array",
+            Self::Exn => "exn",
+            Self::Cont => "cont",
+            Self::None => "none",
+            Self::NoFunc => "nofunc",
+            Self::NoExtern => "noextern",
+            Self::NoExn => "noexn",
+            Self::NoCont => "nocont",
+        })
+    }
+}
+
+impl Display for HeapType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Abstract {
+                share: types::Sharing::Unshared,
+                abstract_type,
+            } => Display::fmt(abstract_type, f),
+            Self::Abstract {
+                share: types::Sharing::Shared,
+                abstract_type,
+            } => write!(f, "(shared {abstract_type})"),
+            Self::Index(index) => Display::fmt(index, f),
+        }
+    }
+}
+
 impl Display for RefType {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        Display::fmt(&ValType::from(*self), f)
+        if let (
+            true,
+            HeapType::Abstract {
+                share: types::Sharing::Unshared,
+                abstract_type,
+            },
+        ) = (self.nullable, self.heap_type)
+        {
+            return f.write_str(match abstract_type {
+                AbstractHeapType::Func => "funcref",
+                AbstractHeapType::Extern => "externref",
+                AbstractHeapType::Any => "anyref",
+                AbstractHeapType::Eq => "eqref",
+                AbstractHeapType::I31 => "i31ref",
+                AbstractHeapType::Struct => "structref",
+                AbstractHeapType::Array => "arrayref",
+                AbstractHeapType::Exn => "exnref",
+                AbstractHeapType::Cont => "contref",
+                AbstractHeapType::None => "nullref",
+                AbstractHeapType::NoFunc => "nullfuncref",
+                AbstractHeapType::NoExtern => "nullexternref",
+                AbstractHeapType::NoExn => "nullexnref",
+                AbstractHeapType::NoCont => "nullcontref",
+            });
+        }
+
+        f.write_str("(ref ")?;
+        if self.nullable {
+            f.write_str("null ")?;
+        }
+        write!(f, "{})", self.heap_type)
     }
 }
 
@@ -174,6 +444,32 @@ pub struct TableType {
     pub limits: types::Limits,
 }
 
+impl TableType {
+    /// The integer type used to index into the table.
+    ///
+    /// This is the type of the index operands of the table instructions, such as `table.get`,
+    /// `table.set`, `table.size`, and `table.grow`. Tables with 64-bit indices were introduced as
+    /// part of the [*memory64* proposal].
+    ///
+    /// [*memory64* proposal]: https://github.com/WebAssembly/memory64
+    #[inline]
+    pub fn index_type(&self) -> types::IdxType {
+        self.limits.index_type()
+    }
+
+    /// Gets the proposals that are required to use this table type.
+    ///
+    /// **`shared`** tables require the
+    /// [shared-everything threads proposal](WasmFeatures::SHARED_EVERYTHING_THREADS).
+    pub fn required_features(&self) -> WasmFeatures {
+        let features = self.element_type.required_features() | self.limits.required_features();
+        match self.limits.share {
+            types::Sharing::Shared => features | WasmFeatures::SHARED_EVERYTHING_THREADS,
+            types::Sharing::Unshared => features,
+        }
+    }
+}
+
 /// Represents a [WebAssembly memory type].
 ///
 /// [WebAssembly memory type]: https://webassembly.github.io/spec/core/binary/types.html#memory-types
@@ -183,6 +479,36 @@ pub struct MemType {
     pub limits: types::Limits,
 }
 
+impl MemType {
+    /// The integer type used to index into the linear memory.
+    ///
+    /// This is the type of the address operands of the memory instructions.
+    #[inline]
+    pub fn index_type(&self) -> types::IdxType {
+        self.limits.index_type()
+    }
+
+    /// The size of a page of the linear memory, in bytes.
+    ///
+    /// See the documentation for [`Limits::page_size_log2`](types::Limits::page_size_log2) for
+    /// more information.
+    #[inline]
+    pub fn page_size(&self) -> u32 {
+        self.limits.page_size()
+    }
+
+    /// Gets the proposals that are required to use this memory type.
+    ///
+    /// **`shared`** memories require the [threads proposal](WasmFeatures::ATOMICS).
+    pub fn required_features(&self) -> WasmFeatures {
+        let features = self.limits.required_features();
+        match self.limits.share {
+            types::Sharing::Shared => features | WasmFeatures::ATOMICS,
+            types::Sharing::Unshared => features,
+        }
+    }
+}
+
 impl From<types::Limits> for MemType {
     #[inline]
     fn from(limits: types::Limits) -> Self {
@@ -204,3 +530,11 @@ pub enum TagType {
     /// values that are thrown and caught.
     Exception(crate::module::TypeIdx),
 }
+
+impl TagType {
+    /// Gets the proposals that are required to use this tag type.
+    #[inline]
+    pub const fn required_features(&self) -> WasmFeatures {
+        WasmFeatures::EXCEPTION_HANDLING
+    }
+}
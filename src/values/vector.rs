@@ -19,6 +19,8 @@ pub enum InvalidVector {
     Length,
     #[non_exhaustive]
     Remaining { expected: u32 },
+    #[non_exhaustive]
+    TrailingBytes,
 }
 
 crate::static_assert::check_size!(InvalidVector, <= 8);
@@ -28,6 +30,7 @@ impl core::fmt::Display for InvalidVector {
         match self {
             Self::Length => f.write_str("expected item count prefix for vector"),
             Self::Remaining { expected } => write!(f, "expected {expected} more items in vector"),
+            Self::TrailingBytes => f.write_str("expected end of input after last item in vector"),
         }
     }
 }
@@ -16,6 +16,7 @@ where
     remaining: usize,
     input: &'a [u8],
     parser: P,
+    expect_eof: bool,
     _marker: core::marker::PhantomData<fn() -> crate::Parsed<'a, T, E>>,
 }
 
@@ -31,10 +32,38 @@ where
             remaining: remaining.to_usize(),
             input,
             parser,
+            expect_eof: false,
             _marker: core::marker::PhantomData,
         }
     }
 
+    /// Causes the iterator to return an error if any input remains after all of the vector's
+    /// elements have been parsed.
+    ///
+    /// The error is returned in place of the last element, or as the only item if the vector is
+    /// empty. This is useful when the vector makes up the entire contents of some larger
+    /// structure, such as a module section.
+    #[inline]
+    pub fn expect_eof(mut self) -> Self {
+        self.expect_eof = true;
+        self
+    }
+
+    /// Returns `true` if the only item left to return is the error for the trailing bytes.
+    #[inline]
+    fn trailing_bytes(&self) -> bool {
+        self.expect_eof && self.remaining == 0 && !self.input.is_empty()
+    }
+
+    fn trailing_bytes_error(&mut self) -> nom::Err<E> {
+        self.expect_eof = false;
+        nom::Err::Failure(E::from_error_kind_and_cause(
+            self.input,
+            error::ErrorKind::Eof,
+            error::ErrorCause::Vector(values::InvalidVector::TrailingBytes),
+        ))
+    }
+
     /// Creates an [`Iterator`] for parsing a vector from the given `input` with a parsed
     /// [*LEB128* encoded length], using the given [`Parser`].
     ///
@@ -68,9 +97,15 @@ where
                 Ok((input, ok)) => {
                     self.remaining = next_remaining;
                     self.input = input;
-                    Ok(ok)
+
+                    if self.trailing_bytes() {
+                        Err(self.trailing_bytes_error())
+                    } else {
+                        Ok(ok)
+                    }
                 }
                 Err(err) => {
+                    self.expect_eof = false;
                     let expected = core::mem::replace(&mut self.remaining, 0)
                         .try_into()
                         .unwrap_or(u32::MAX);
@@ -84,15 +119,18 @@ where
                     }))
                 }
             })
+        } else if self.trailing_bytes() {
+            Some(Err(self.trailing_bytes_error()))
         } else {
             None
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        let trailing = usize::from(self.trailing_bytes());
         (
-            !self.input.is_empty() as usize,
-            Some(self.input.len().min(self.remaining)),
+            usize::from(!self.input.is_empty() && self.remaining > 0).max(trailing),
+            Some(self.input.len().min(self.remaining) + trailing),
         )
     }
 }
@@ -104,7 +142,7 @@ where
 {
     #[inline]
     fn len(&self) -> usize {
-        self.remaining
+        self.remaining + usize::from(self.trailing_bytes())
     }
 }
 
@@ -122,7 +160,10 @@ where
 {
     #[inline]
     fn clone(&self) -> Self {
-        Self::new(self.remaining, self.input, self.parser.clone())
+        Self {
+            expect_eof: self.expect_eof,
+            ..Self::new(self.remaining, self.input, self.parser.clone())
+        }
     }
 }
 
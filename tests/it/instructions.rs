@@ -1,6 +1,24 @@
 use nom_wasm::{error::VerboseError, isa::instructions};
 use std::fmt::Write;
 
+/// Parses an expression, returning the text of each of its instructions on separate lines.
+fn display_expr(expr: &[u8]) -> String {
+    let mut results = allocator_api2::vec::Vec::new();
+
+    let (remaining, _) =
+        nom_wasm::isa::expr::<_, VerboseError>(expr, instructions::Parser::new(&mut results))
+            .unwrap();
+
+    assert!(remaining.is_empty());
+
+    let mut text = String::new();
+    for instr in results.into_iter() {
+        let _ = writeln!(&mut text, "{instr}");
+    }
+
+    text
+}
+
 #[test]
 fn basic_expr() {
     let expr: &[u8] = &[0x20, 0x00, 0x41, 0x2A, 0x6A, 0x0F, 0x01, 0x0B];
@@ -13,5 +31,472 @@ fn basic_expr() {
         let _ = writeln!(&mut text, "{instr}");
     }
 
-    insta::assert_display_snapshot!(&text);
+    insta::assert_snapshot!(&text);
+}
+
+#[test]
+fn select_typed_ref() {
+    let expr: &[u8] = &[0x1C, 0x01, 0x64, 0x6D, 0x0B];
+    insta::assert_snapshot!(display_expr(expr));
+}
+
+#[test]
+fn gc_expr() {
+    let expr: &[u8] = &[
+        0xFB, 0x00, 0x01, // struct.new 1
+        0xFB, 0x02, 0x01, 0x00, // struct.get 1 0
+        0xFB, 0x08, 0x02, 0x03, // array.new_fixed 2 3
+        0xFB, 0x11, 0x02, 0x04, // array.copy 2 4
+        0xFB, 0x15, 0x6C, // ref.test (ref null i31)
+        0xFB, 0x16, 0x00, // ref.cast (ref 0)
+        0xFB, 0x18, 0x01, 0x00, 0x6E, 0x6B, // br_on_cast 0 anyref (ref struct)
+        0xFB, 0x1C, // ref.i31
+        0xFB, 0x1E, // i31.get_u
+        0xD3, // ref.eq
+        0x0B,
+    ];
+    insta::assert_snapshot!(display_expr(expr));
+}
+
+#[test]
+fn br_on_cast_invalid_flags() {
+    // The flags byte is the last byte of the input
+    let error = nom_wasm::isa::instr::<_, VerboseError>(&[0xFB, 0x18, 0x04], ()).unwrap_err();
+    insta::assert_snapshot!(error.to_string());
+}
+
+#[test]
+fn function_references_expr() {
+    let expr: &[u8] = &[
+        0xD0, 0x00, // ref.null 0
+        0xD0, 0x6E, // ref.null any
+        0xD4, // ref.as_non_null
+        0xD5, 0x00, // br_on_null 0
+        0xD6, 0x01, // br_on_non_null 1
+        0x14, 0x02, // call_ref 2
+        0x15, 0x03, // return_call_ref 3
+        0x0B,
+    ];
+    insta::assert_snapshot!(display_expr(expr));
+}
+
+#[test]
+fn try_table_expr() {
+    let expr: &[u8] = &[
+        0x1F, 0x7F, // try_table (result i32)
+        0x03, // catch clause count
+        0x00, 0x00, 0x00, // catch 0 0
+        0x01, 0x01, 0x00, // catch_ref 1 0
+        0x03, 0x01, // catch_all_ref 1
+        0x41, 0x2A, // i32.const 42
+        0x0B, // end
+        0x0A, // throw_ref
+        0x0B,
+    ];
+    insta::assert_snapshot!(display_expr(expr));
+}
+
+#[test]
+fn extended_const_expr() {
+    use nom_wasm::{isa::ConstValue, storage::DefaultHeap};
+
+    let expr = nom_wasm::isa::Expr::parse::<VerboseError>(&[
+        0x23, 0x00, // global.get 0
+        0x41, 0x10, // i32.const 16
+        0x6C, // i32.mul
+        0x41, 0x04, // i32.const 4
+        0x6A, // i32.add
+        0x0B,
+    ])
+    .unwrap()
+    .1;
+
+    let value = expr
+        .evaluate::<VerboseError, _>(&[ConstValue::I32(3)], DefaultHeap)
+        .unwrap();
+    assert_eq!(value, ConstValue::I32(52));
+
+    let unknown_global = expr
+        .evaluate::<VerboseError, _>(&[], DefaultHeap)
+        .unwrap_err();
+    insta::assert_snapshot!(unknown_global.to_string());
+
+    let non_constant = nom_wasm::isa::Expr::parse::<VerboseError>(&[0x20, 0x00, 0x0B])
+        .unwrap()
+        .1
+        .evaluate::<VerboseError, _>(&[], DefaultHeap)
+        .unwrap_err();
+    insta::assert_snapshot!(non_constant.to_string());
+
+    // 20 operands are pushed before any of them are added together
+    let mut deep = [0x41, 0x01].repeat(20);
+    deep.extend([0x6A].repeat(19));
+    deep.push(0x0B);
+    let sum = nom_wasm::isa::Expr::parse::<VerboseError>(&deep)
+        .unwrap()
+        .1
+        .evaluate::<VerboseError, _>(&[], DefaultHeap)
+        .unwrap();
+    assert_eq!(sum, ConstValue::I32(20));
+}
+
+#[test]
+fn wide_arithmetic_and_fp16_expr() {
+    let expr: &[u8] = &[
+        0xFC, 0x13, // i64.add128
+        0xFC, 0x16, // i64.mul_wide_u
+        0xFC, 0x30, 0x00, 0x08, // f32.load_f16 offset=8 align=1
+        0xFD, 0xA0, 0x02, // f16x8.splat
+        0xFD, 0xA1, 0x02, 0x07, // f16x8.extract_lane 7
+        0xFD, 0xCB, 0x02, // f32x4.promote_low_f16x8
+        0xFD, 0xCE, 0x02, // f16x8.relaxed_madd
+        0xFC, 0x31, 0x00, 0x00, // f32.store_f16 align=1
+        0x0B,
+    ];
+    insta::assert_snapshot!(display_expr(expr));
+}
+
+#[test]
+fn stack_switching_expr() {
+    let expr: &[u8] = &[
+        0xD0, 0x68, // ref.null cont
+        0x1A, // drop
+        0xD2, 0x00, // ref.func 0
+        0xE0, 0x01, // cont.new 1
+        0xE1, 0x01, 0x02, // cont.bind 1 2
+        0xE3, 0x02, // resume 2
+        0x02, // handler count
+        0x00, 0x00, 0x01, // (on 0 1)
+        0x01, 0x01, // (on 1 switch)
+        0xE4, 0x02, 0x00, 0x00, // resume_throw 2 0
+        0xE2, 0x01, // suspend 1
+        0xE5, 0x02, 0x01, // switch 2 1
+        0x0B,
+    ];
+    insta::assert_snapshot!(display_expr(expr));
+}
+
+#[test]
+fn shared_everything_threads_expr() {
+    let expr: &[u8] = &[
+        0xD0, 0x65, 0x6E, // ref.null (shared any)
+        0x1A, // drop
+        0xFE, 0x4F, 0x01, 0x00, // global.atomic.get acqrel 0
+        0xFE, 0x57, 0x00, 0x01, // global.atomic.rmw.cmpxchg seqcst 1
+        0xFE, 0x5B, 0x00, 0x02, // table.atomic.rmw.cmpxchg seqcst 2
+        0xFE, 0x60, 0x01, 0x03, 0x04, // struct.atomic.rmw.add acqrel 3 4
+        0xFE, 0x67, 0x00, 0x05, // array.atomic.get seqcst 5
+        0xFB, 0x1F, // ref.i31_shared
+        0x0B,
+    ];
+    insta::assert_snapshot!(display_expr(expr));
+}
+
+#[test]
+fn mvp_and_simd_features() {
+    use nom_wasm::{
+        features::WasmFeatures,
+        isa::{FCPrefixedOpcode, Opcode, V128Opcode},
+    };
+
+    let features = WasmFeatures::MVP | WasmFeatures::SIMD128;
+
+    assert_eq!(Opcode::I32Add.proposal(), WasmFeatures::MVP);
+    assert_eq!(
+        Opcode::from(V128Opcode::I8x16Swizzle).proposal(),
+        WasmFeatures::SIMD128
+    );
+    assert_eq!(
+        Opcode::from(FCPrefixedOpcode::MemoryCopy).proposal(),
+        WasmFeatures::BULK_MEMORY
+    );
+
+    let allowed: &[u8] = &[
+        0x02, 0x7B, // block (result v128)
+        0x41, 0x01, // i32.const 1
+        0xFD, 0x0F, // i8x16.splat
+        0x0B, // end
+        0x1A, // drop
+        0x0B,
+    ];
+
+    let (remaining, _) =
+        nom_wasm::isa::expr_with_features::<_, VerboseError>(allowed, features, ()).unwrap();
+    assert!(remaining.is_empty());
+
+    let mut errors = String::new();
+    for disallowed in [
+        &[0x12, 0x00, 0x0B][..],       // return_call 0
+        &[0x02, 0x00, 0x0B, 0x0B][..], // block (type 0)
+        &[0xD0, 0x6F, 0x0B][..],       // ref.null extern
+    ] {
+        let error = nom_wasm::isa::expr_with_features::<_, VerboseError>(disallowed, features, ())
+            .unwrap_err();
+        let _ = writeln!(&mut errors, "{error}");
+    }
+
+    let error = nom_wasm::types::ValType::parse_with_features::<VerboseError>(&[0x6F], features)
+        .unwrap_err();
+    let _ = writeln!(&mut errors, "{error}");
+
+    insta::assert_snapshot!(errors);
+}
+
+#[test]
+fn multi_memory_and_extended_const_features() {
+    use nom_wasm::features::WasmFeatures;
+
+    let allowed: &[u8] = &[
+        0x3F, 0x00, // memory.size 0
+        0x41, 0x00, // i32.const 0
+        0x11, 0x00, 0x00, // call_indirect 0 0
+        0x1A, // drop
+        0x0B,
+    ];
+
+    let (remaining, _) =
+        nom_wasm::isa::expr_with_features::<_, VerboseError>(allowed, WasmFeatures::MVP, ())
+            .unwrap();
+    assert!(remaining.is_empty());
+
+    let mut errors = String::new();
+    for disallowed in [
+        &[0x3F, 0x01, 0x0B][..],               // memory.size 1
+        &[0x41, 0x00, 0x11, 0x00, 0x01, 0x0B], // call_indirect 1 0
+    ] {
+        let error =
+            nom_wasm::isa::expr_with_features::<_, VerboseError>(disallowed, WasmFeatures::MVP, ())
+                .unwrap_err();
+        let _ = writeln!(&mut errors, "{error}");
+    }
+
+    // i32.const 1, i32.const 2, i32.add
+    let extended: &[u8] = &[0x41, 0x01, 0x41, 0x02, 0x6A, 0x0B];
+    let error =
+        nom_wasm::isa::const_expr_with_features::<_, VerboseError>(extended, WasmFeatures::MVP, ())
+            .unwrap_err();
+    let _ = writeln!(&mut errors, "{error}");
+
+    let (remaining, _) = nom_wasm::isa::const_expr_with_features::<_, VerboseError>(
+        extended,
+        WasmFeatures::MVP | WasmFeatures::EXTENDED_CONST,
+        (),
+    )
+    .unwrap();
+    assert!(remaining.is_empty());
+
+    insta::assert_snapshot!(errors);
+}
+
+#[test]
+fn func_validator() {
+    use nom_wasm::{
+        isa::{FuncValidator, ModuleContext},
+        module::{ElemIdx, FuncBody, FuncIdx, GlobalIdx, MemIdx, TableIdx, TypeIdx},
+        storage::DefaultHeap,
+        types::{GlobalType, MemType, RefType, TableType, ValType},
+    };
+
+    struct Context {
+        types: Vec<(Vec<ValType>, Vec<ValType>)>,
+        funcs: Vec<TypeIdx>,
+        globals: Vec<GlobalType>,
+        memories: Vec<MemType>,
+        tables: Vec<TableType>,
+    }
+
+    impl ModuleContext for Context {
+        fn type_parameters(&self, index: TypeIdx) -> Option<&[ValType]> {
+            let (parameters, _) = self.types.get(u32::from(index) as usize)?;
+            Some(parameters)
+        }
+
+        fn type_results(&self, index: TypeIdx) -> Option<&[ValType]> {
+            let (_, results) = self.types.get(u32::from(index) as usize)?;
+            Some(results)
+        }
+
+        fn func(&self, index: FuncIdx) -> Option<TypeIdx> {
+            self.funcs.get(u32::from(index) as usize).copied()
+        }
+
+        fn is_func_declared(&self, _: FuncIdx) -> bool {
+            false
+        }
+
+        fn table(&self, index: TableIdx) -> Option<TableType> {
+            self.tables.get(u32::from(index) as usize).copied()
+        }
+
+        fn memory(&self, index: MemIdx) -> Option<MemType> {
+            self.memories.get(u32::from(index) as usize).copied()
+        }
+
+        fn global(&self, index: GlobalIdx) -> Option<GlobalType> {
+            self.globals.get(u32::from(index) as usize).copied()
+        }
+
+        fn elem(&self, _: ElemIdx) -> Option<RefType> {
+            None
+        }
+
+        fn data_count(&self) -> Option<u32> {
+            None
+        }
+    }
+
+    let context = Context {
+        types: vec![
+            (vec![ValType::I32, ValType::I32], vec![ValType::I32]),
+            (Vec::new(), Vec::new()),
+        ],
+        funcs: vec![TypeIdx::from(0)],
+        globals: vec![
+            GlobalType::parse::<VerboseError>(&[0x7F, 0x00]).unwrap().1,
+            GlobalType::parse::<VerboseError>(&[0x7E, 0x01]).unwrap().1,
+        ],
+        memories: vec![MemType::parse::<VerboseError>(&[0x00, 0x01]).unwrap().1],
+        tables: vec![
+            TableType::parse::<VerboseError>(&[0x70, 0x00, 0x01])
+                .unwrap()
+                .1,
+        ],
+    };
+
+    let mut validator = FuncValidator::new(&context, DefaultHeap);
+    let mut validate = |signature: u32, contents: &[u8]| {
+        let mut bytes = vec![contents.len() as u8];
+        bytes.extend_from_slice(contents);
+        let (_, body) = FuncBody::parse::<VerboseError>(&bytes).unwrap();
+        body.validate::<_, _, VerboseError>(TypeIdx::from(signature), &mut validator)
+            .map_err(|e| (validator.offset(), e.to_string()))
+    };
+
+    validate(
+        0,
+        &[
+            0x01, 0x01, 0x7E, // (local i64)
+            0x20, 0x00, // local.get 0
+            0x20, 0x01, // local.get 1
+            0x6A, // i32.add
+            0x20, 0x00, // local.get 0
+            0x04, 0x7F, // if (result i32)
+            0x41, 0x01, // i32.const 1
+            0x05, // else
+            0x41, 0x02, // i32.const 2
+            0x0B, // end
+            0x6A, // i32.add
+            0x23, 0x01, // global.get 1
+            0x21, 0x02, // local.set 2
+            0x41, 0x00, // i32.const 0
+            0x28, 0x02, 0x00, // i32.load align=4
+            0x6A, // i32.add
+            0x0B,
+        ],
+    )
+    .unwrap();
+
+    validate(
+        0,
+        &[
+            0x00, // (no locals)
+            0x00, // unreachable
+            0x6A, // i32.add
+            0x0B,
+        ],
+    )
+    .unwrap();
+
+    validate(
+        1,
+        &[
+            0x00, // (no locals)
+            0x02, 0x40, // block
+            0x41, 0x00, // i32.const 0
+            0x0D, 0x00, // br_if 0
+            0x41, 0x00, // i32.const 0
+            0x41, 0x00, // i32.const 0
+            0x10, 0x00, // call 0
+            0x1A, // drop
+            0x0B, // end
+            0xD0, 0x71, // ref.null none
+            0xD0, 0x6C, // ref.null i31
+            0xD3, // ref.eq
+            0x1A, // drop
+            0x0B,
+        ],
+    )
+    .unwrap();
+
+    let mut errors = String::new();
+    for (signature, contents) in [
+        (0, &[0x00, 0x42, 0x00, 0x0B][..]),             // i64.const 0
+        (0, &[0x00, 0x6A, 0x0B][..]),                   // i32.add
+        (1, &[0x00, 0x41, 0x01, 0x0B][..]),             // i32.const 1
+        (0, &[0x00, 0x20, 0x05, 0x0B][..]),             // local.get 5
+        (1, &[0x00, 0x41, 0x00, 0x24, 0x00, 0x0B][..]), // global.set 0
+        (1, &[0x00, 0x0C, 0x01, 0x0B][..]),             // br 1
+        (1, &[0x00, 0x41, 0x00, 0x28, 0x03, 0x00, 0x1A, 0x0B][..]), // i32.load align=8
+        (1, &[0x00, 0xD2, 0x00, 0x1A, 0x0B][..]),       // ref.func 0
+        (1, &[0x00, 0x05, 0x0B][..]),                   // else
+        (1, &[0x00, 0xD0, 0x70, 0xD0, 0x70, 0xD3, 0x1A, 0x0B][..]), // ref.eq funcref funcref
+        (5, &[0x00, 0x0B][..]),
+        (1, &[0x00, 0xD0, 0x05, 0x1A, 0x0B][..]), // ref.null 5
+        (
+            1,
+            &[0x00, 0x41, 0x00, 0xFE, 0x10, 0x02, 0x00, 0x1A, 0x0B][..],
+        ), // i32.atomic.load
+        (1, &[0x00, 0x08, 0x00, 0x0B][..]),       // throw 0
+        (1, &[0x00, 0xD0, 0x70, 0xD4, 0x1A, 0x0B][..]), // ref.as_non_null
+    ] {
+        let (offset, error) = validate(signature, contents).unwrap_err();
+        let _ = writeln!(&mut errors, "{offset}: {error}");
+    }
+
+    insta::assert_snapshot!(errors);
+}
+
+#[test]
+fn table_index_type() {
+    use nom_wasm::{
+        module::TableIdx,
+        types::{IdxType, TableType},
+    };
+
+    let table32 = TableType::parse::<VerboseError>(&[0x70, 0x00, 0x01])
+        .unwrap()
+        .1;
+    let table64 = TableType::parse::<VerboseError>(&[0x70, 0x04, 0x01])
+        .unwrap()
+        .1;
+    let tables = [table32, table64];
+    let table_type = |table: TableIdx| tables.get(usize::try_from(table.0).unwrap()).copied();
+
+    // table.get 1, table.size 0, table.copy 1 1, table.copy 0 1, call_indirect 0 1, table.get 2,
+    // i32.const 0, end
+    let expr: &[u8] = &[
+        0x25, 0x01, 0xFC, 0x10, 0x00, 0xFC, 0x0E, 0x01, 0x01, 0xFC, 0x0E, 0x00, 0x01, 0x11, 0x00,
+        0x01, 0x25, 0x02, 0x41, 0x00, 0x0B,
+    ];
+    let mut results = allocator_api2::vec::Vec::new();
+    nom_wasm::isa::expr::<_, VerboseError>(expr, instructions::Parser::new(&mut results)).unwrap();
+
+    let index_types = results
+        .iter()
+        .map(|instr| instr.table_index_type(table_type))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        index_types,
+        [
+            Some(IdxType::I64),
+            Some(IdxType::I32),
+            Some(IdxType::I64),
+            Some(IdxType::I32),
+            Some(IdxType::I64),
+            None,
+            None,
+            None,
+        ]
+    );
 }
@@ -63,3 +63,1052 @@ fn import_sec_example() {
 
     insta::assert_debug_snapshot!(result);
 }
+
+#[test]
+fn func_sec_example() {
+    let bytes = [
+        3, // count
+        0, // typeidx
+        1, // typeidx
+        0, // typeidx
+    ];
+
+    let result = nom_wasm::module::FuncSec::parse::<VerboseError>(&bytes)
+        .and_then(|funcs| funcs.iter_contents().collect::<Result<Vec<_>, _>>());
+
+    insta::assert_debug_snapshot!(result);
+}
+
+#[test]
+fn func_sec_trailing_bytes() {
+    for bytes in [&[1, 0, 0xFF][..], &[0, 0xFF]] {
+        let funcs = nom_wasm::module::FuncSec::parse::<VerboseError>(bytes).unwrap();
+        let mut iter = funcs.iter_contents::<VerboseError>();
+
+        assert_eq!(iter.len(), 1);
+        assert!(iter.next().unwrap().is_err());
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+    }
+}
+
+#[test]
+fn table_sec_example() {
+    let bytes = [
+        2,    // count
+        0x70, // funcref
+        1,    // limits with maximum
+        1,    // minimum
+        2,    // maximum
+        0x40, // table with initializer
+        0,    // reserved
+        0x70, // funcref
+        0,    // limits w/o maximum
+        4,    // minimum
+        0xD0, // ref.null
+        0x70, // func
+        0x0B, // end
+    ];
+
+    let result = nom_wasm::module::TableSec::parse::<VerboseError>(&bytes)
+        .and_then(|tables| tables.iter_contents().collect::<Result<Vec<_>, _>>());
+
+    insta::assert_debug_snapshot!(result);
+}
+
+#[test]
+fn global_sec_example() {
+    let bytes = [
+        2,    // count
+        0x7F, // i32
+        0,    // immutable
+        0x41, // i32.const
+        42,   // value
+        0x0B, // end
+        0x7E, // i64
+        1,    // mutable
+        0x23, // global.get
+        0,    // globalidx
+        0x0B, // end
+    ];
+
+    let result = nom_wasm::module::GlobalSec::parse::<VerboseError>(&bytes)
+        .and_then(|globals| globals.iter_contents().collect::<Result<Vec<_>, _>>());
+
+    insta::assert_debug_snapshot!(result);
+}
+
+#[test]
+fn global_sec_init_exprs() {
+    use nom_wasm::isa::instructions;
+    use std::fmt::Write as _;
+
+    let bytes = [
+        2,    // count
+        0x7F, // i32
+        0,    // immutable
+        0x41, // i32.const
+        42,   // value
+        0x0B, // end
+        0x7F, // i32
+        0,    // immutable
+        0x23, // global.get
+        0,    // globalidx
+        0x41, // i32.const
+        8,    // value
+        0x6A, // i32.add
+        0x0B, // end
+    ];
+
+    let globals = nom_wasm::module::GlobalSec::parse::<VerboseError>(&bytes).unwrap();
+    let mut text = String::new();
+    for global in globals.iter_contents::<VerboseError>() {
+        let mut results = allocator_api2::vec::Vec::new();
+        global
+            .unwrap()
+            .parse_init_with::<_, VerboseError>(instructions::Parser::new(&mut results))
+            .unwrap();
+
+        for instr in results.into_iter() {
+            let _ = writeln!(&mut text, "{instr}");
+        }
+        text.push('\n');
+    }
+
+    insta::assert_snapshot!(text);
+}
+
+#[test]
+fn shared_global_and_table_types() {
+    let globals = [
+        2,    // count
+        0x64, // ref
+        0x65, // shared
+        0x6E, // any
+        2,    // shared immutable
+        0xD0, // ref.null
+        0x65, // shared
+        0x71, // none
+        0x0B, // end
+        0x7F, // i32
+        3,    // shared mutable
+        0x41, // i32.const
+        0,    // value
+        0x0B, // end
+    ];
+
+    let global_types = nom_wasm::module::GlobalSec::parse::<VerboseError>(&globals)
+        .and_then(|globals| globals.iter_contents().collect::<Result<Vec<_>, _>>())
+        .unwrap()
+        .into_iter()
+        .map(|global| global.global_type)
+        .collect::<Vec<_>>();
+
+    assert_eq!(global_types[0].share, nom_wasm::types::Sharing::Shared);
+    assert_eq!(
+        global_types[0].mutability,
+        nom_wasm::types::Mutability::Constant
+    );
+    assert_eq!(global_types[0].value_type.to_string(), "(ref (shared any))");
+    assert_eq!(global_types[1].share, nom_wasm::types::Sharing::Shared);
+    assert_eq!(
+        global_types[1].mutability,
+        nom_wasm::types::Mutability::Variable
+    );
+
+    let invalid_global = [1, 0x7F, 4, 0x41, 0, 0x0B];
+    let error = nom_wasm::module::GlobalSec::parse::<VerboseError>(&invalid_global)
+        .and_then(|globals| globals.iter_contents().collect::<Result<Vec<_>, _>>())
+        .unwrap_err();
+    assert!(error.to_string().contains("GlobalType"), "{error}");
+
+    let tables = [
+        1,    // count
+        0x63, // ref null
+        0x65, // shared
+        0x70, // func
+        3,    // shared limits with maximum
+        1,    // minimum
+        2,    // maximum
+    ];
+
+    let table_types = nom_wasm::module::TableSec::parse::<VerboseError>(&tables)
+        .and_then(|tables| tables.iter_contents().collect::<Result<Vec<_>, _>>())
+        .unwrap();
+
+    insta::assert_debug_snapshot!(table_types);
+}
+
+#[test]
+fn shared_ref_type_shorthand() {
+    use nom_wasm::types::{AbstractHeapType, BlockType, HeapType, RefType, Sharing, ValType};
+
+    let bytes = [0x65, 0x70]; // (shared funcref)
+    let expected = RefType::new(
+        true,
+        HeapType::Abstract {
+            share: Sharing::Shared,
+            abstract_type: AbstractHeapType::Func,
+        },
+    );
+
+    let (_, ref_type) = RefType::parse::<VerboseError>(&bytes).unwrap();
+    assert_eq!(ref_type, expected);
+    let (_, value_type) = ValType::parse::<VerboseError>(&bytes).unwrap();
+    assert_eq!(value_type, ValType::Ref(expected));
+    let (_, block_type) = BlockType::parse::<VerboseError>(&bytes).unwrap();
+    assert_eq!(block_type, BlockType::Inline(ValType::Ref(expected)));
+
+    // Only abstract heap types can be shared
+    assert!(ValType::parse::<VerboseError>(&[0x65, 0x00]).is_err());
+}
+
+#[test]
+fn export_sec_example() {
+    let bytes = [
+        3, // count
+        3, b'a', b'd', b'd', // name
+        0,    // func
+        1,    // funcidx
+        6, b'm', b'e', b'm', b'o', b'r', b'y', // name
+        2,    // mem
+        0,    // memidx
+        3, b'a', b'd', b'd', // name
+        3,    // global
+        0,    // globalidx
+    ];
+
+    let exports = nom_wasm::module::ExportSec::parse::<VerboseError>(&bytes).unwrap();
+
+    assert_eq!(
+        exports.find::<VerboseError>("memory"),
+        Ok(Some(nom_wasm::module::ExportDesc::Mem(0u32.into())))
+    );
+    assert_eq!(exports.find::<VerboseError>("table"), Ok(None));
+
+    let result = exports
+        .iter_contents::<VerboseError>()
+        .collect::<Result<Vec<_>, _>>();
+
+    insta::assert_debug_snapshot!(result);
+
+    let duplicate = exports.check_duplicate_names::<VerboseError>();
+
+    insta::assert_debug_snapshot!(duplicate);
+
+    // The unknown tag is the last byte of the input
+    let unknown = nom_wasm::module::ExportDesc::parse::<VerboseError>(&[0x05]).unwrap_err();
+    insta::assert_snapshot!(unknown.to_string());
+}
+
+#[test]
+fn start_and_data_count_sec_example() {
+    let bytes = [
+        0, b'a', b's', b'm', // magic
+        1, 0, 0, 0,  // version
+        8,  // start section
+        1,  // length
+        3,  // funcidx
+        12, // data count section
+        1,  // length
+        0,  // count
+    ];
+
+    let module = nom_wasm::module::Module::parse::<VerboseError>(&bytes).unwrap();
+
+    assert_eq!(module.start, Some(3u32.into()));
+    assert_eq!(module.data_count, Some(0));
+
+    let start = nom_wasm::module::start_sec::<VerboseError>(&[3, 0]).unwrap_err();
+    insta::assert_snapshot!(start.to_string());
+
+    let data_count = nom_wasm::module::data_count_sec::<VerboseError>(&[0, 0]).unwrap_err();
+    insta::assert_snapshot!(data_count.to_string());
+}
+
+#[test]
+fn elem_sec_example() {
+    let bytes = [
+        8, // count
+        0, // active, table 0, funcidx
+        0x41, 0, 0x0B, // offset
+        2, 1, 2, // funcidx vector
+        1, // passive, elemkind
+        0, // funcref
+        1, 3, // funcidx vector
+        2, // active, explicit table index, elemkind
+        1, // tableidx
+        0x41, 4, 0x0B, // offset
+        0,    // funcref
+        1, 5, // funcidx vector
+        3, // declarative, elemkind
+        0, // funcref
+        1, 6, // funcidx vector
+        4, // active, table 0, expr
+        0x41, 0, 0x0B, // offset
+        1, 0xD2, 7, 0x0B, // expr vector
+        5,    // passive, reftype
+        0x6F, // externref
+        1, 0xD0, 0x6F, 0x0B, // expr vector
+        6,    // active, explicit table index, reftype
+        2,    // tableidx
+        0x41, 8, 0x0B, // offset
+        0x70, // funcref
+        1, 0xD2, 9, 0x0B, // expr vector
+        7,    // declarative, reftype
+        0x70, // funcref
+        1, 0xD2, 10, 0x0B, // expr vector
+    ];
+
+    let result = nom_wasm::module::ElemSec::parse::<VerboseError>(&bytes)
+        .and_then(|elems| elems.iter_contents().collect::<Result<Vec<_>, _>>());
+
+    insta::assert_debug_snapshot!(result);
+}
+
+#[test]
+fn elem_segment_invalid_flags() {
+    // Flags of 0x88, encoded in two bytes
+    let elem =
+        nom_wasm::module::ElemSegment::parse::<VerboseError>(&[0x88, 0x01, 0x0B]).unwrap_err();
+    insta::assert_snapshot!(elem.to_string());
+}
+
+#[test]
+fn data_segment_invalid_flags() {
+    use nom_wasm::error::InvalidFlags;
+
+    // Passive with an explicit memory index
+    let data = nom_wasm::module::DataSegment::parse::<VerboseError>(&[3, 0, 0x0B]).unwrap_err();
+    insta::assert_snapshot!(data.to_string());
+
+    let messages = [
+        InvalidFlags::ElemSegment(core::num::NonZeroU32::new(0x88)),
+        InvalidFlags::DataSegment(core::num::NonZeroU32::new(3)),
+        InvalidFlags::DataSegment(core::num::NonZeroU32::new(5)),
+    ]
+    .map(|flags| flags.to_string());
+    insta::assert_snapshot!(messages.join("\n"));
+}
+
+#[test]
+fn code_sec_example() {
+    let bytes = [
+        2,    // count
+        4,    // size
+        0,    // local declaration count
+        0x41, // i32.const
+        1,    // value
+        0x0B, // end
+        8,    // size
+        2,    // local declaration count
+        1,    // count
+        0x7F, // i32
+        2,    // count
+        0x7E, // i64
+        0x20, // local.get
+        2,    // localidx
+        0x0B, // end
+    ];
+
+    let code = nom_wasm::module::CodeSec::parse::<VerboseError>(&bytes).unwrap();
+    let bodies = code
+        .iter_contents::<VerboseError>()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    for body in bodies.iter() {
+        body.parse_expr_with::<_, VerboseError>(()).unwrap();
+    }
+
+    insta::assert_debug_snapshot!(bodies);
+}
+
+#[test]
+fn code_sec_locals_overflow() {
+    let bytes = [
+        10,   // size
+        2,    // local declaration count
+        0xFF, // count
+        0xFF, 0xFF, 0xFF, 0x0F, 0x7F, // i32
+        1,    // count
+        0x7E, // i64
+        0x0B, // end
+    ];
+
+    let (_, body) = nom_wasm::module::FuncBody::parse::<VerboseError>(&bytes).unwrap();
+    let result = body
+        .locals::<VerboseError>()
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>();
+
+    insta::assert_debug_snapshot!(result);
+}
+
+#[test]
+fn data_sec_example() {
+    let bytes = [
+        3,    // count
+        0,    // active, memory 0
+        0x41, // i32.const
+        8,    // value
+        0x0B, // end
+        2,    // length
+        0xAB, 0xCD, // data
+        1,    // passive
+        3,    // length
+        b'a', b'b', b'c', // data
+        2,    // active, explicit memory index
+        1,    // memidx
+        0x41, // i32.const
+        0,    // value
+        0x0B, // end
+        0,    // length
+    ];
+
+    let result = nom_wasm::module::DataSec::parse::<VerboseError>(&bytes)
+        .and_then(|data| data.iter_contents().collect::<Result<Vec<_>, _>>());
+
+    insta::assert_debug_snapshot!(result);
+}
+
+#[test]
+fn data_count_mismatch() {
+    let bytes = [
+        0, b'a', b's', b'm', // magic
+        1, 0, 0, 0,  // version
+        12, // data count section
+        1,  // length
+        2,  // count
+        11, // data section
+        4,  // length
+        1,  // count
+        1,  // passive
+        1,  // length
+        0,  // data
+    ];
+
+    let result = nom_wasm::module::Module::parse::<VerboseError>(&bytes).map(|_| ());
+
+    insta::assert_debug_snapshot!(result);
+}
+
+#[test]
+fn tag_sec_example() {
+    let bytes = [
+        0, b'a', b's', b'm', // magic
+        1, 0, 0, 0,  // version
+        5,  // memory section
+        3,  // length
+        1,  // count
+        0,  // limits w/o maximum
+        1,  // minimum
+        13, // tag section
+        3,  // length
+        1,  // count
+        0,  // exception
+        0,  // typeidx
+        6,  // global section
+        1,  // length
+        0,  // count
+    ];
+
+    let module = nom_wasm::module::Module::parse::<VerboseError>(&bytes).unwrap();
+    let tags = module
+        .tag_sec
+        .iter_contents::<VerboseError>()
+        .collect::<Result<Vec<_>, _>>();
+
+    insta::assert_debug_snapshot!(tags);
+}
+
+#[test]
+fn import_sec_trailing_bytes() {
+    let bytes = [
+        1, // count
+        3, b'e', b'n', b'v', // module
+        1, b'f', // name
+        0,    // func
+        0,    // typeidx
+        0xFF, // trailing byte
+    ];
+
+    let result = nom_wasm::module::ImportSec::parse::<VerboseError>(&bytes)
+        .and_then(|imports| imports.iter_contents().collect::<Result<Vec<_>, _>>());
+
+    insta::assert_debug_snapshot!(result);
+}
+
+#[test]
+fn import_sec_compact() {
+    let mut bytes = Vec::with_capacity(64);
+    bytes.extend([
+        3, // count
+        3, // module name length
+    ]);
+    bytes.extend(b"env");
+    bytes.extend([
+        0,    // empty name
+        0x7F, // group
+        2,    // import count
+        1,    // name length
+        b'f', // name
+        0,    // import func
+        0,    // typeidx
+        1,    // name length
+        b'm', // name
+        2,    // import memory
+        0,    // limit w/o maximum
+        1,    // limit minimum
+        2,    // module name length
+    ]);
+    bytes.extend(b"rt");
+    bytes.extend([
+        0,    // empty name
+        0x7E, // group with shared desc
+        0,    // import func
+        1,    // typeidx
+        2,    // import count
+        1,    // name length
+        b'a', // name
+        1,    // name length
+        b'b', // name
+        2,    // module name length
+    ]);
+    bytes.extend(b"rt");
+    bytes.push(1); // name length
+    bytes.push(b'c');
+    bytes.extend([
+        0, // import func
+        2, // typeidx
+    ]);
+
+    let imports = nom_wasm::module::ImportSec::parse::<VerboseError>(&bytes).unwrap();
+    assert_eq!(imports.count(), 3);
+    assert_eq!(imports.iter_entries::<VerboseError>().count(), 3);
+
+    let result = imports
+        .iter_contents::<VerboseError>()
+        .collect::<Result<Vec<_>, _>>();
+
+    insta::assert_debug_snapshot!(result);
+
+    // A group can only be parsed as an `ImportEntry`
+    let group = nom_wasm::module::Import::parse::<VerboseError>(&bytes[1..]).unwrap_err();
+    insta::assert_snapshot!(group.to_string());
+}
+
+#[test]
+fn type_sec_iter_contents() {
+    let bytes = [
+        2,    // count
+        0x60, // func
+        2,    // parameter count
+        0x7F, // i32
+        0x7D, // f32
+        0,    // result count
+        0x60, // func
+        0,    // parameter count
+        1,    // result count
+        0x70, // funcref
+    ];
+
+    let result = nom_wasm::module::TypeSec::parse::<VerboseError>(&bytes).and_then(|types| {
+        types
+            .iter_contents()
+            .map(|rec_type| rec_type.map(|rec_type| rec_type.as_func_type().unwrap()))
+            .collect::<Result<Vec<_>, _>>()
+    });
+
+    insta::assert_debug_snapshot!(result);
+}
+
+#[derive(Default)]
+struct BranchHintPrinter {
+    output: String,
+}
+
+impl<'a> nom_wasm::isa::ParseInstr<'a, VerboseError<'a>> for BranchHintPrinter {
+    fn block(
+        &mut self,
+        _: nom_wasm::types::BlockType,
+    ) -> nom_wasm::isa::Result<(), VerboseError<'a>> {
+        self.output.push_str("block\n");
+        Ok(())
+    }
+
+    fn r#if(
+        &mut self,
+        _: nom_wasm::types::BlockType,
+    ) -> nom_wasm::isa::Result<(), VerboseError<'a>> {
+        self.output.push_str("if\n");
+        Ok(())
+    }
+
+    fn br_if(
+        &mut self,
+        label: nom_wasm::isa::LabelIdx,
+    ) -> nom_wasm::isa::Result<(), VerboseError<'a>> {
+        self.output.push_str(&format!("br_if {label}\n"));
+        Ok(())
+    }
+
+    fn local_get(
+        &mut self,
+        local: nom_wasm::module::LocalIdx,
+    ) -> nom_wasm::isa::Result<(), VerboseError<'a>> {
+        self.output.push_str(&format!("local.get {local}\n"));
+        Ok(())
+    }
+
+    fn nop(&mut self) -> nom_wasm::isa::Result<(), VerboseError<'a>> {
+        self.output.push_str("nop\n");
+        Ok(())
+    }
+
+    fn end(&mut self) -> nom_wasm::isa::Result<(), VerboseError<'a>> {
+        self.output.push_str("end\n");
+        Ok(())
+    }
+}
+
+impl<'a> nom_wasm::isa::ParseBranchHint<'a, VerboseError<'a>> for BranchHintPrinter {
+    fn branch_hint(
+        &mut self,
+        likelihood: nom_wasm::module::custom::BranchLikelihood,
+    ) -> nom_wasm::isa::Result<(), VerboseError<'a>> {
+        self.output.push_str(&format!("(; {likelihood} ;) "));
+        Ok(())
+    }
+}
+
+#[test]
+fn branch_hint_sec_example() {
+    let hints = [
+        1, // count
+        0, // funcidx
+        3, // hint count
+        3, 1, 1, // offset 3 likely (local.get, ignored)
+        5, 1, 1, // offset 5 likely
+        10, 1, 0, // offset 10 unlikely
+    ];
+
+    let body = [
+        15, // size
+        0,  // local declaration count
+        0x02, 0x40, // block
+        0x20, 0, // local.get 0
+        0x0D, 0,    // br_if 0
+        0x0B, // end
+        0x20, 0, // local.get 0
+        0x04, 0x40, // if
+        0x01, // nop
+        0x0B, // end
+        0x0B, // end
+    ];
+
+    let section = nom_wasm::module::custom::CustomSection {
+        name: nom_wasm::module::custom::BranchHintSec::NAME,
+        contents: &hints,
+    };
+
+    let hints =
+        nom_wasm::module::custom::BranchHintSec::interpret_custom_section::<VerboseError>(&section)
+            .unwrap()
+            .unwrap();
+
+    insta::assert_debug_snapshot!(hints);
+
+    let func_hints = hints.find::<VerboseError>(0.into()).unwrap().unwrap();
+    let (_, body) = nom_wasm::module::FuncBody::parse::<VerboseError>(&body).unwrap();
+    let printer = body
+        .parse_expr_with_branch_hints::<_, VerboseError>(&func_hints, BranchHintPrinter::default())
+        .unwrap();
+
+    insta::assert_snapshot!(printer.output);
+
+    let invalid =
+        nom_wasm::module::custom::FuncBranchHints::parse::<VerboseError>(&[0, 1, 0, 1, 2])
+            .unwrap_err();
+    insta::assert_snapshot!(invalid.to_string());
+}
+
+#[derive(Default)]
+struct RecTypePrinter {
+    output: String,
+}
+
+impl nom_wasm::types::ParseResultType for &mut RecTypePrinter {
+    fn with_count(&mut self, count: usize) {
+        self.output.push_str(&format!(" ({count})"));
+    }
+
+    fn next_type(&mut self, value_type: nom_wasm::types::ValType) {
+        self.output.push_str(&format!(" {value_type}"));
+    }
+}
+
+impl nom_wasm::types::ParseFuncType for RecTypePrinter {
+    type ResultType<'a> = &'a mut Self;
+
+    fn parameters(&mut self) -> Self::ResultType<'_> {
+        self.output.push_str("  func param");
+        self
+    }
+
+    fn results(&mut self) -> Self::ResultType<'_> {
+        self.output.push_str(" result");
+        self
+    }
+}
+
+impl nom_wasm::types::ParseRecType for RecTypePrinter {
+    type FuncType<'a> = &'a mut Self;
+
+    fn rec_group(&mut self, count: usize) {
+        self.output.push_str(&format!("\nrec ({count})"));
+    }
+
+    fn sub_type(&mut self, is_final: bool, supertype_count: usize) {
+        let sub = if is_final { "sub final" } else { "sub" };
+        self.output
+            .push_str(&format!("\n {sub} ({supertype_count})"));
+    }
+
+    fn supertype(&mut self, index: nom_wasm::types::TypeIdx) {
+        self.output.push_str(&format!(" {index}"));
+    }
+
+    fn shared_type(&mut self) {
+        self.output.push_str(" shared");
+    }
+
+    fn func_type(&mut self) -> Self::FuncType<'_> {
+        self.output.push('\n');
+        self
+    }
+
+    fn struct_type(&mut self, field_count: usize) {
+        self.output.push_str(&format!("\n  struct ({field_count})"));
+    }
+
+    fn struct_field(&mut self, field: nom_wasm::types::FieldType) {
+        self.output
+            .push_str(&format!(" {:?} {}", field.mutability, field.storage_type));
+    }
+
+    fn array_type(&mut self, element: nom_wasm::types::FieldType) {
+        self.output.push_str(&format!(
+            "\n  array {:?} {}",
+            element.mutability, element.storage_type
+        ));
+    }
+
+    fn cont_type(&mut self, func_type: nom_wasm::types::TypeIdx) {
+        self.output.push_str(&format!("\n  cont {func_type}"));
+    }
+}
+
+#[test]
+fn type_sec_rec_types() {
+    let bytes = [
+        3,    // count
+        0x60, // func
+        1,    // parameter count
+        0x7F, // i32
+        0,    // result count
+        0x4E, // rec
+        2,    // count
+        0x50, // sub
+        0,    // supertype count
+        0x5F, // struct
+        2,    // field count
+        0x78, // i8
+        1,    // mutable
+        0x7E, // i64
+        0,    // immutable
+        0x4F, // sub final
+        1,    // supertype count
+        1,    // typeidx
+        0x5F, // struct
+        0,    // field count
+        0x5E, // array
+        0x77, // i16
+        1,    // mutable
+    ];
+
+    let types = nom_wasm::module::TypeSec::parse::<VerboseError>(&bytes).unwrap();
+    let printer = types
+        .parse_rec_types_with::<_, VerboseError>(RecTypePrinter::default())
+        .unwrap();
+
+    insta::assert_snapshot!(printer.output);
+    insta::assert_debug_snapshot!(types);
+
+    let rec_types = types
+        .iter_contents::<VerboseError>()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(rec_types.len(), types.count());
+    assert!(rec_types[0].as_func_type().is_some());
+    assert_eq!(rec_types[1].len(), 2);
+    assert!(rec_types[1].as_func_type().is_none());
+}
+
+#[test]
+fn type_sec_cont_types() {
+    let bytes = [
+        2,    // count
+        0x60, // func
+        1,    // parameter count
+        0x7F, // i32
+        0,    // result count
+        0x5D, // cont
+        0,    // typeidx
+    ];
+
+    let printer = nom_wasm::module::TypeSec::parse::<VerboseError>(&bytes)
+        .and_then(|types| types.parse_rec_types_with(RecTypePrinter::default()))
+        .unwrap();
+
+    insta::assert_snapshot!(printer.output);
+}
+
+#[test]
+fn type_sec_shared_types() {
+    let bytes = [
+        1,    // count
+        0x65, // shared
+        0x5F, // struct
+        1,    // field count
+        0x7F, // i32
+        1,    // mutable
+    ];
+
+    let printer = nom_wasm::module::TypeSec::parse::<VerboseError>(&bytes)
+        .and_then(|types| types.parse_rec_types_with(RecTypePrinter::default()))
+        .unwrap();
+
+    insta::assert_snapshot!(printer.output);
+}
+
+#[test]
+fn type_sec_typed_refs() {
+    let bytes = [
+        1,    // count
+        0x60, // func
+        4,    // parameter count
+        0x63, // ref null
+        0x00, // typeidx
+        0x64, // ref
+        0x6E, // any
+        0x6C, // i31ref
+        0x64, // ref
+        0x73, // nofunc
+        1,    // result count
+        0x63, // ref null
+        0x69, // exn
+    ];
+
+    let printer = nom_wasm::module::TypeSec::parse::<VerboseError>(&bytes)
+        .and_then(|types| types.parse_rec_types_with(RecTypePrinter::default()))
+        .unwrap();
+
+    insta::assert_snapshot!(printer.output);
+}
+
+#[test]
+fn table64_and_memory64_limits() {
+    let tables = [
+        1,     // count
+        0x6F,  // externref
+        0b101, // limits with i64 index and maximum
+        0x80, 0x80, 0x80, 0x80, 0x10, // minimum (2^32)
+        0x80, 0x80, 0x80, 0x80, 0x20, // maximum (2^33)
+    ];
+
+    let table_types = nom_wasm::module::TableSec::parse::<VerboseError>(&tables)
+        .and_then(|tables| tables.iter_contents().collect::<Result<Vec<_>, _>>())
+        .unwrap()
+        .into_iter()
+        .map(|table| (table.table_type.index_type(), table.table_type.limits))
+        .collect::<Vec<_>>();
+
+    let memories = [
+        1,     // count
+        0b111, // limits with i64 index, shared, and maximum
+        1,     // minimum
+        2,     // maximum
+    ];
+
+    let mem_types = nom_wasm::module::MemSec::parse::<VerboseError>(&memories)
+        .and_then(|memories| memories.iter_contents().collect::<Result<Vec<_>, _>>())
+        .unwrap()
+        .into_iter()
+        .map(|mem| (mem.index_type(), mem.limits))
+        .collect::<Vec<_>>();
+
+    insta::assert_debug_snapshot!((table_types, mem_types));
+}
+
+#[test]
+fn mem_sec_custom_page_sizes() {
+    let bytes = [
+        2,      // count
+        0b1001, // limits with page size and maximum
+        1,      // minimum
+        0x80, 0x02, // maximum
+        0,    // page size log2
+        0,    // limits w/o maximum
+        1,    // minimum
+    ];
+
+    let page_sizes = nom_wasm::module::MemSec::parse::<VerboseError>(&bytes)
+        .and_then(|memories| memories.iter_contents().collect::<Result<Vec<_>, _>>())
+        .unwrap()
+        .into_iter()
+        .map(|mem| mem.page_size())
+        .collect::<Vec<_>>();
+
+    assert_eq!(page_sizes, [1, 65536]);
+
+    let invalid = [
+        1,      // count
+        0b1000, // limits with page size
+        1,      // minimum
+        12,     // page size log2
+    ];
+
+    let result = nom_wasm::module::MemSec::parse::<VerboseError>(&invalid)
+        .and_then(|memories| memories.iter_contents().collect::<Result<Vec<_>, _>>());
+
+    insta::assert_debug_snapshot!(result);
+}
+
+#[test]
+fn type_sec_features() {
+    use nom_wasm::features::WasmFeatures;
+
+    let bytes = [
+        3,    // count
+        0x60, // func
+        1,    // parameter count
+        0x7F, // i32
+        0,    // result count
+        0x60, // func
+        1,    // parameter count
+        0x7B, // v128
+        0,    // result count
+        0x5E, // array
+        0x77, // i16
+        1,    // mutable
+    ];
+
+    let types =
+        nom_wasm::module::TypeSec::parse_with_features::<VerboseError>(&bytes, WasmFeatures::MVP)
+            .unwrap();
+
+    let results = types.iter_contents::<VerboseError>().collect::<Vec<_>>();
+    assert!(results[0].is_ok());
+    insta::assert_debug_snapshot!(&results[1..]);
+
+    let error = types
+        .parse_rec_types_with::<_, VerboseError>(RecTypePrinter::default())
+        .map(|_| ())
+        .unwrap_err();
+    insta::assert_snapshot!(error.to_string());
+
+    let all = nom_wasm::module::TypeSec::parse_with_features::<VerboseError>(
+        &bytes,
+        WasmFeatures::MVP | WasmFeatures::SIMD128 | WasmFeatures::GC,
+    )
+    .unwrap();
+    assert!(all
+        .iter_contents::<VerboseError>()
+        .all(|result| result.is_ok()));
+}
+
+#[test]
+fn module_features() {
+    use nom_wasm::{features::WasmFeatures, module::Module};
+
+    let header = [0, b'a', b's', b'm', 1, 0, 0, 0];
+
+    let two_memories = [
+        &header[..],
+        &[
+            2, // import section
+            8, // length
+            1, // count
+            1, b'm', // module name
+            1, b'x', // name
+            2,    // memory
+            0,    // limits w/o maximum
+            0,    // minimum
+            5,    // memory section
+            3,    // length
+            1,    // count
+            0,    // limits w/o maximum
+            1,    // minimum
+        ],
+    ]
+    .concat();
+
+    let error = Module::parse_with_features::<VerboseError>(&two_memories, WasmFeatures::MVP)
+        .map(|_| ())
+        .unwrap_err();
+    insta::assert_snapshot!(error.to_string());
+
+    Module::parse_with_features::<VerboseError>(
+        &two_memories,
+        WasmFeatures::MVP | WasmFeatures::MULTI_MEMORY,
+    )
+    .unwrap();
+
+    let tags = [
+        &header[..],
+        &[
+            13, // tag section
+            1,  // length
+            0,  // count
+        ],
+    ]
+    .concat();
+
+    let error = Module::parse_with_features::<VerboseError>(&tags, WasmFeatures::MVP)
+        .map(|_| ())
+        .unwrap_err();
+    insta::assert_snapshot!(error.to_string());
+
+    let limits = [
+        &header[..],
+        &[
+            4,    // table section
+            4,    // length
+            1,    // count
+            0x70, // funcref
+            4,    // 64-bit limits w/o maximum
+            1,    // minimum
+            6,    // global section
+            6,    // length
+            1,    // count
+            0x7F, // i32
+            0,    // immutable
+            0x41, // i32.const
+            0,    // 0
+            0x0B, // end
+        ],
+    ]
+    .concat();
+
+    let module = Module::parse_with_features::<VerboseError>(&limits, WasmFeatures::MVP).unwrap();
+    let tables = module
+        .table_sec
+        .iter_contents::<VerboseError>()
+        .collect::<Result<Vec<_>, _>>();
+    insta::assert_debug_snapshot!(tables);
+
+    let globals = module.global_sec.iter_contents::<VerboseError>();
+    assert!(globals.into_iter().all(|result| result.is_ok()));
+}